@@ -9,8 +9,14 @@
 //!
 //!
 use crate::errors::{Error, Result};
+use crate::io_utils::WktObjects;
 use crate::model::*;
-use crate::parser::{parse, Attribute, Processor};
+use crate::parser::{parse_chained, unescape, Attribute, Processor};
+use crate::report::Diagnostics;
+
+use std::borrow::Cow;
+use std::cell::RefCell;
+use std::io::{BufReader, Read};
 
 #[allow(non_camel_case_types)]
 #[allow(clippy::upper_case_acronyms)]
@@ -21,59 +27,422 @@ pub enum Node<'a> {
     METHOD(Method<'a>),
     PARAMETER(Parameter<'a>),
     DATUM(Datum<'a>),
-    PROJCRS(Projcs<'a>),
+    PROJCRS(Box<Projcs<'a>>),
     GEOGCRS(Geogcs<'a>),
+    GEOCCRS(Geogcs<'a>),
+    CS(CoordinateSystem),
+    USAGE(UsageInfo<'a>),
+    SCOPE(&'a str),
+    AREA(&'a str),
+    BBOX([f64; 4]),
+    GEOIDMODEL(GeoidModel<'a>),
+    AXIS(Axis<'a>),
     PROJECTION(Projection<'a>),
     ELLIPSOID(Ellipsoid<'a>),
-    COMPOUNDCRS(Compoundcrs<'a>),
+    PRIMEM(PrimeMeridian<'a>),
+    COMPOUNDCRS(Box<Compoundcrs<'a>>),
     VERTICALCRS(Verticalcrs<'a>),
     TOWGS84(Vec<&'a str>),
+    NADGRIDS(&'a str),
+    REMARK(&'a str),
+    COORDINATEMETADATA(CoordinateMetadata<'a>),
+    DERIVEDPROJCRS(Box<DerivedProjcrs<'a>>),
+    EPOCH(f64),
+    MERIDIAN(f64),
+    ORDER(u8),
     OTHER(&'a str),
 }
 
+impl<'a> Node<'a> {
+    /// Return the name of a top-level CRS node
+    ///
+    /// Escaped double quotes of the WKT name are unescaped,
+    /// see [`unescape`].
+    pub fn crs_name(&self) -> Option<Cow<'a, str>> {
+        match self {
+            Self::PROJCRS(cs) => Some(cs.name),
+            Self::GEOGCRS(cs) | Self::GEOCCRS(cs) => Some(cs.name),
+            Self::COMPOUNDCRS(crs) => Some(crs.name),
+            Self::VERTICALCRS(crs) => Some(crs.name),
+            Self::COORDINATEMETADATA(md) => return md.crs.crs_name(),
+            Self::DERIVEDPROJCRS(crs) => Some(crs.name),
+            _ => None,
+        }
+        .map(unescape)
+    }
+
+    /// Return the kind of a top-level CRS node
+    pub fn crs_kind(&self) -> Option<CrsKind> {
+        match self {
+            Self::GEOGCRS(_) => Some(CrsKind::Geographic),
+            Self::PROJCRS(_) => Some(CrsKind::Projected),
+            Self::COMPOUNDCRS(_) => Some(CrsKind::Compound),
+            Self::GEOCCRS(_) => Some(CrsKind::Geocentric),
+            Self::VERTICALCRS(_) => Some(CrsKind::Vertical),
+            Self::COORDINATEMETADATA(md) => md.crs.crs_kind(),
+            Self::DERIVEDPROJCRS(_) => Some(CrsKind::Projected),
+            _ => None,
+        }
+    }
+
+    /// Return the authority of a top-level CRS node
+    pub fn authority(&self) -> Option<&Authority<'a>> {
+        match self {
+            Self::PROJCRS(cs) => cs.authority.as_ref(),
+            Self::GEOGCRS(cs) | Self::GEOCCRS(cs) => cs.authority.as_ref(),
+            Self::COMPOUNDCRS(crs) => crs.authority.as_ref(),
+            Self::VERTICALCRS(crs) => crs.authority.as_ref(),
+            Self::COORDINATEMETADATA(md) => md.crs.authority(),
+            _ => None,
+        }
+    }
+
+    /// Return the geodetic datum of a top-level CRS node
+    pub fn datum(&self) -> Option<&Datum<'a>> {
+        match self {
+            Self::PROJCRS(cs) => Some(&cs.geogcs.datum),
+            Self::GEOGCRS(cs) | Self::GEOCCRS(cs) => Some(&cs.datum),
+            Self::COMPOUNDCRS(crs) => match &crs.h_crs {
+                Horizontalcrs::Projcs(cs) => Some(&cs.geogcs.datum),
+                Horizontalcrs::Geogcs(cs) => Some(&cs.datum),
+            },
+            Self::COORDINATEMETADATA(md) => md.crs.datum(),
+            Self::DERIVEDPROJCRS(crs) => crs.base.datum(),
+            _ => None,
+        }
+    }
+
+    /// Return the base geographic crs of a top-level
+    /// projected CRS node
+    pub fn base_geographic(&self) -> Option<&Geogcs<'a>> {
+        match self {
+            Self::PROJCRS(cs) => Some(&cs.geogcs),
+            Self::COMPOUNDCRS(crs) => match &crs.h_crs {
+                Horizontalcrs::Projcs(cs) => Some(&cs.geogcs),
+                Horizontalcrs::Geogcs(_) => None,
+            },
+            Self::COORDINATEMETADATA(md) => md.crs.base_geographic(),
+            Self::DERIVEDPROJCRS(crs) => crs.base.base_geographic(),
+            _ => None,
+        }
+    }
+
+    /// Return the projection parameters of a top-level
+    /// projected CRS node
+    pub fn parameters(&self) -> Option<&[Parameter<'a>]> {
+        match self {
+            Self::PROJCRS(cs) => Some(&cs.projection.parameters),
+            Self::COMPOUNDCRS(crs) => match &crs.h_crs {
+                Horizontalcrs::Projcs(cs) => Some(&cs.projection.parameters),
+                Horizontalcrs::Geogcs(_) => None,
+            },
+            Self::COORDINATEMETADATA(md) => md.crs.parameters(),
+            Self::DERIVEDPROJCRS(crs) => crs.base.parameters(),
+            _ => None,
+        }
+    }
+
+    /// Return the remark of a top-level CRS node
+    ///
+    /// Escaped double quotes of the WKT remark are unescaped,
+    /// see [`unescape`].
+    pub fn remark(&self) -> Option<Cow<'a, str>> {
+        match self {
+            Self::PROJCRS(cs) => cs.remark,
+            Self::GEOGCRS(cs) | Self::GEOCCRS(cs) => cs.remark,
+            Self::COMPOUNDCRS(crs) => crs.remark,
+            Self::VERTICALCRS(crs) => crs.remark,
+            Self::COORDINATEMETADATA(md) => return md.crs.remark(),
+            _ => None,
+        }
+        .map(unescape)
+    }
+
+    /// Return the bounding box of the area of use of a top-level
+    /// CRS node as (south, west, north, east)
+    pub fn bounding_box(&self) -> Option<[f64; 4]> {
+        match self {
+            Self::PROJCRS(cs) => cs.bounding_box(),
+            Self::GEOGCRS(cs) | Self::GEOCCRS(cs) => cs.bounding_box(),
+            Self::COMPOUNDCRS(crs) => crs.bounding_box(),
+            Self::COORDINATEMETADATA(md) => md.crs.bounding_box(),
+            _ => None,
+        }
+    }
+}
+
+/// Parse a WKT string with a default [`Builder`]
+///
+/// Example:
+///
+/// ```
+/// use proj4wkt::{Error, Node};
+///
+/// fn crs_name(wkt: &str) -> Result<Option<String>, Error> {
+///     let node: Node = wkt.try_into()?;
+///     Ok(node.crs_name().map(String::from))
+/// }
+///
+/// let wkt = concat!(
+///     r#"GEOGCS["WGS 84",DATUM["WGS_1984",SPHEROID["WGS 84",6378137,298.257223563]],"#,
+///     r#"PRIMEM["Greenwich",0],UNIT["degree",0.0174532925199433]]"#,
+/// );
+/// assert_eq!(crs_name(wkt).unwrap().as_deref(), Some("WGS 84"));
+/// assert!(crs_name("GEOGCS(").is_err());
+/// ```
+impl<'a> TryFrom<&'a str> for Node<'a> {
+    type Error = Error;
+
+    fn try_from(s: &'a str) -> Result<Self> {
+        Builder::new().parse(s)
+    }
+}
+
+impl<'a> TryFrom<&'a String> for Node<'a> {
+    type Error = Error;
+
+    fn try_from(s: &'a String) -> Result<Self> {
+        Builder::new().parse(s)
+    }
+}
+
+/// A WKT CRS owning its WKT string
+///
+/// Returned by [`Builder::parse_reader`] since nodes
+/// borrow their input.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OwnedNode {
+    wkt: String,
+}
+
+impl OwnedNode {
+    /// Return the WKT string
+    pub fn as_str(&self) -> &str {
+        &self.wkt
+    }
+
+    /// Parse the WKT string and return the root node
+    ///
+    /// Nodes are not cached: the whole WKT string is parsed
+    /// again on each call, keep the returned node instead of
+    /// calling this method repeatedly.
+    ///
+    /// The string is validated on creation with the default
+    /// [`Builder`] options, an error is not expected.
+    pub fn node(&self) -> Result<Node<'_>> {
+        Builder::new().parse(&self.wkt)
+    }
+
+    /// Return the WKT string
+    pub fn into_string(self) -> String {
+        self.wkt
+    }
+}
+
+/// Parse and validate an owned WKT string
+impl TryFrom<String> for OwnedNode {
+    type Error = Error;
+
+    fn try_from(wkt: String) -> Result<Self> {
+        Builder::new().parse(&wkt)?;
+        Ok(Self { wkt })
+    }
+}
+
 /// A WKT CRS builder
 ///
 /// A builder implement the WKT CRS grammar and create a syntactic
 /// representation of the WKT.
 ///
-#[derive(Debug, Default)]
-pub struct Builder;
+#[derive(Debug, Default, Clone)]
+pub struct Builder {
+    lenient_units: bool,
+}
 
 impl Builder {
     /// Create a new Builder
     pub fn new() -> Self {
-        Builder
+        Self::default()
+    }
+
+    /// Treat an obviously linear unit of a geographic crs as
+    /// a missing angular unit (i.e degree) instead of failing
+    ///
+    /// Some malformed WKT put the projected crs unit in place of
+    /// the geographic crs unit. A warning is added to the
+    /// diagnostics returned by
+    /// [`parse_with_diagnostics`](Self::parse_with_diagnostics).
+    /// Disabled by default.
+    pub fn lenient_units(mut self, enable: bool) -> Self {
+        self.lenient_units = enable;
+        self
     }
 
     /// Parse a WKT string and return the root Node
+    ///
+    /// On failure, the returned error is chained with
+    /// errors from nested nodes.
     pub fn parse<'a>(&self, s: &'a str) -> Result<Node<'a>> {
-        parse(s, self)
+        self.parse_with_diagnostics(s).map(|(node, _)| node)
+    }
+
+    /// Parse a WKT string and return the root Node with
+    /// the warnings raised while building it
+    pub fn parse_with_diagnostics<'a>(&self, s: &'a str) -> Result<(Node<'a>, Diagnostics)> {
+        let (result, diagnostics) = self.parse_collect(s);
+        result.map(|node| (node, diagnostics))
+    }
+
+    // Return the parse result with the warnings raised
+    // before completion or failure
+    pub(crate) fn parse_collect<'a>(&self, s: &'a str) -> (Result<Node<'a>>, Diagnostics) {
+        let builder = self.node_builder();
+        let result = parse_chained(s, &builder);
+        (result, builder.diagnostics.into_inner())
+    }
+
+    fn node_builder(&self) -> NodeBuilder {
+        NodeBuilder {
+            lenient_units: self.lenient_units,
+            diagnostics: RefCell::default(),
+        }
+    }
+
+    /// Parse a WKT byte slice and return the root Node
+    ///
+    /// The input must be valid UTF-8, a leading BOM is ignored.
+    pub fn parse_bytes<'a>(&self, b: &'a [u8]) -> Result<Node<'a>> {
+        let b = b.strip_prefix(b"\xEF\xBB\xBF").unwrap_or(b);
+        self.parse(std::str::from_utf8(b)?)
+    }
+
+    /// Parse the concatenated WKT CRS of a reader
+    ///
+    /// Objects are read one at a time without loading the
+    /// whole input. An invalid object yields an error and
+    /// iteration goes on with the next object, I/O errors
+    /// end the iteration.
+    pub fn parse_reader<R: Read>(r: R) -> impl Iterator<Item = Result<OwnedNode>> {
+        WktObjects::new(BufReader::new(r)).map(|wkt| wkt.and_then(OwnedNode::try_from))
     }
 }
 
+// Warnings are logged but not collected when the builder
+// is used directly as a processor.
 impl<'a> Processor<'a> for Builder {
     type Err = Error;
     type Output = Node<'a>;
 
-    fn process<I>(&self, key: &'a str, _depth: usize, attrs: I) -> Result<Self::Output, Self::Err>
+    fn process<I>(&self, key: &'a str, depth: usize, attrs: I) -> Result<Self::Output, Self::Err>
     where
         I: Iterator<Item = Attribute<'a, Self::Output>>,
     {
+        self.node_builder().process(key, depth, attrs)
+    }
+
+    fn nested_error(&self, key: &'a str, err: Self::Err) -> Self::Err {
+        nested_error(key, err)
+    }
+}
+
+// Build the nodes of a single parse and collect
+// its warnings
+#[derive(Debug)]
+struct NodeBuilder {
+    lenient_units: bool,
+    diagnostics: RefCell<Diagnostics>,
+}
+
+impl<'a> Processor<'a> for NodeBuilder {
+    type Err = Error;
+    type Output = Node<'a>;
+
+    fn process<I>(&self, key: &'a str, depth: usize, attrs: I) -> Result<Self::Output, Self::Err>
+    where
+        I: Iterator<Item = Attribute<'a, Self::Output>>,
+    {
+        if let Some(_msg) = depth_warning(key, depth) {
+            crate::log::warn!("{_msg}");
+        }
+        self.process_node(key, attrs)
+    }
+
+    fn nested_error(&self, key: &'a str, err: Self::Err) -> Self::Err {
+        nested_error(key, err)
+    }
+}
+
+// Chain the error of a nested node
+fn nested_error(key: &str, err: Error) -> Error {
+    Error::context(format!("Invalid {key}"), err)
+}
+
+impl NodeBuilder {
+    fn process_node<'a>(
+        &self,
+        key: &'a str,
+        attrs: impl Iterator<Item = Attribute<'a, Node<'a>>>,
+    ) -> Result<Node<'a>> {
         match key {
-            "AUTHORITY" | "ID" => self.authority(attrs).map(Node::AUTHORITY),
-            "PROJCS" | "PROJCRS" | "PROJECTEDCRS" => self.projcs(attrs).map(Node::PROJCRS),
+            "AUTHORITY" | "ID" => self.authority(key, attrs).map(Node::AUTHORITY),
+            "PROJCS" | "PROJCRS" | "PROJECTEDCRS" | "BASEPROJCRS" => self
+                .projcs(key, attrs)
+                .map(|cs| Node::PROJCRS(Box::new(cs))),
+            "DERIVEDPROJCRS" => self
+                .derived_projcrs(key, attrs)
+                .map(|crs| Node::DERIVEDPROJCRS(Box::new(crs))),
             "GEOGCS" | "GEOGCRS" | "GEOGRAPHICCRS" | "BASEGEODCRS" | "BASEGEOGCRS" => {
-                self.geogcs(attrs).map(Node::GEOGCRS)
+                self.geogcs(false, attrs).map(Node::GEOGCRS)
             }
-            "ELLIPSOID" | "SPHEROID" => self.ellipsoid(attrs).map(Node::ELLIPSOID),
+            "GEOCCS" => self.geogcs(true, attrs).map(Node::GEOCCRS),
+            "GEODCRS" | "GEODETICCRS" => self.geogcs(false, attrs).map(|cs| {
+                if matches!(&cs.cs, Some(c) if c.cs_type == CsType::Cartesian) {
+                    Node::GEOCCRS(cs)
+                } else {
+                    Node::GEOGCRS(cs)
+                }
+            }),
+            "CS" => self.coordinate_system(attrs).map(Node::CS),
+            "USAGE" => self.usage(attrs).map(Node::USAGE),
+            "REMARK" => self.text(key, attrs).map(Node::REMARK),
+            "VERTICALEXTENT" | "TEMPORALEXTENT" => {
+                // Metadata not relevant for conversion
+                for _ in attrs {}
+                Ok(Node::OTHER(key))
+            }
+            "SCOPE" => self.text(key, attrs).map(Node::SCOPE),
+            "AREA" => self.text(key, attrs).map(Node::AREA),
+            "BBOX" => self.bbox(attrs).map(Node::BBOX),
+            "GEOIDMODEL" => self.geoid_model(attrs).map(Node::GEOIDMODEL),
+            "AXIS" => self.axis(attrs).map(Node::AXIS),
+            "MERIDIAN" => self.meridian(attrs).map(Node::MERIDIAN),
+            "ORDER" => self.order(attrs).map(Node::ORDER),
+            "ELLIPSOID" | "SPHEROID" => self.ellipsoid(key, attrs).map(Node::ELLIPSOID),
+            "PRIMEM" | "PRIMEMERIDIAN" => self.prime_meridian(key, attrs).map(Node::PRIMEM),
             "CONVERSION" => self.projection(attrs).map(Node::PROJECTION),
-            "PROJECTION" | "METHOD" => self.method(attrs).map(Node::METHOD),
+            "PROJECTION" | "METHOD" => self.method(key, attrs).map(Node::METHOD),
             "PARAMETER" => self.parameter(attrs).map(Node::PARAMETER),
-            "DATUM" | "GEODETICDATUM" | "TRF" => self.datum(attrs).map(Node::DATUM),
-            "UNIT" => self.unit(key, attrs).map(Node::UNIT),
-            "COMPD_CS" | "COMPOUNDCRS" => self.compoundcrs(attrs).map(Node::COMPOUNDCRS),
+            "DATUM" | "GEODETICDATUM" | "TRF" => self.datum(key, attrs).map(Node::DATUM),
+            "ENSEMBLE" => self.ensemble(key, attrs),
+            "UNIT" | "ANGLEUNIT" | "ANGULARUNIT" | "LENGTHUNIT" | "SCALEUNIT" | "TIMEUNIT" => {
+                self.unit(key, attrs).map(Node::UNIT)
+            }
+            "COMPD_CS" | "COMPOUNDCRS" => self
+                .compoundcrs(key, attrs)
+                .map(|crs| Node::COMPOUNDCRS(Box::new(crs))),
             "VERT_CS" | "VERTCRS" | "VERTICALCRS" => self.verticalcrs(attrs).map(Node::VERTICALCRS),
             "TOWGS84" => self.towgs84(attrs).map(Node::TOWGS84),
+            // Non standard (ESRI, QGIS)
+            "NADGRIDS" => self.text(key, attrs).map(Node::NADGRIDS),
+            "COORDINATEMETADATA" => self
+                .coordinate_metadata(attrs)
+                .map(Node::COORDINATEMETADATA),
+            "EPOCH" => self.epoch(attrs).map(Node::EPOCH),
+            "ENGCRS" | "ENGINEERINGCRS" | "LOCAL_CS" => {
+                // Engineering crs are not supported
+                for _ in attrs {}
+                Ok(Node::OTHER("ENGCRS"))
+            }
             _ => {
                 // Consume tokens
                 for _ in attrs {}
@@ -81,11 +450,9 @@ impl<'a> Processor<'a> for Builder {
             }
         }
     }
-}
-
-impl Builder {
     fn projcs<'a>(
         &self,
+        key: &str,
         attrs: impl Iterator<Item = Attribute<'a, Node<'a>>>,
     ) -> Result<Projcs<'a>> {
         let mut name = None;
@@ -93,7 +460,14 @@ impl Builder {
         let mut projection = None;
         let mut method = None;
         let mut unit = None;
+        let mut cs = None;
         let mut authority = None;
+        let mut usage = None;
+        let mut remark = None;
+        let mut scope = None;
+        let mut area = None;
+        let mut bounding_box = None;
+        let mut axes = vec![];
 
         let mut parameters: Vec<Parameter<'a>> = vec![];
 
@@ -102,9 +476,18 @@ impl Builder {
                 Attribute::Quoted(s) if i == 0 => name = Some(s),
                 Attribute::Keyword(_, n) => match n {
                     Node::GEOGCRS(cs) => geogcs = Some(cs),
+                    Node::USAGE(u) => usage = usage.or(Some(u)),
+                    Node::REMARK(s) => remark = Some(s),
+                    // WKT2 2015: usage is defined at the crs level
+                    Node::SCOPE(s) => scope = Some(s),
+                    Node::AREA(s) => area = Some(s),
+                    Node::BBOX(b) => bounding_box = Some(b),
+                    Node::CS(c) => cs = Some(c),
+                    Node::AXIS(ax) => axes.push(ax),
                     Node::PROJECTION(p) => projection = Some(p),
-                    // Handle WKT1
+                    // Crs authority
                     Node::AUTHORITY(auth) => authority = Some(auth),
+                    // Handle WKT1
                     Node::UNIT(u) => unit = Some(u),
                     Node::METHOD(m) => method = Some(m),
                     Node::PARAMETER(p) => parameters.push(p),
@@ -116,12 +499,20 @@ impl Builder {
 
         // On pre WKT2 parameters for projection are at the root level
         if projection.is_none() {
-            let me = method.ok_or(Error::Wkt("No projection method defined".into()))?;
+            self.dedup_parameters(&mut parameters);
+            let me = method.ok_or_else(|| {
+                // WKT1 parameters without PROJECTION
+                if parameters.is_empty() {
+                    Error::Wkt("missing PROJECTION/CONVERSION node".into())
+                } else {
+                    Error::Wkt("projection has no METHOD".into())
+                }
+            })?;
             projection = Some(Projection {
                 name: "Unknown",
                 method: me,
                 parameters,
-                authority,
+                authority: None,
             });
         }
 
@@ -141,9 +532,86 @@ impl Builder {
 
         Ok(Projcs {
             name: name.unwrap_or("Unknown"),
-            geogcs: geogcs.ok_or(Error::Wkt("Missing PROJCRS geodetic crs".into()))?,
-            projection: projection.ok_or(Error::Wkt("Missing PROJCS projection".into()))?,
+            geogcs: geogcs
+                .ok_or_else(|| Error::Wkt(format!("Missing {key} geodetic crs").into()))?,
+            projection: projection
+                .ok_or_else(|| Error::Wkt(format!("Missing {key} projection").into()))?,
+            unit,
+            cs,
+            usage: usage.or_else(|| usage_2015(scope, area, bounding_box)),
+            axes,
+            authority,
+            remark,
+        })
+    }
+
+    fn axis<'a>(&self, attrs: impl Iterator<Item = Attribute<'a, Node<'a>>>) -> Result<Axis<'a>> {
+        let mut name = None;
+        let mut direction = None;
+        let mut meridian = None;
+        let mut unit = None;
+        let mut order = None;
+
+        for (i, a) in attrs.enumerate() {
+            match a {
+                Attribute::Quoted(s) if i == 0 => name = Some(s),
+                Attribute::Label(s) if i == 1 => {
+                    direction = Some(match s.to_ascii_lowercase().as_str() {
+                        "north" => AxisDirection::North,
+                        "south" => AxisDirection::South,
+                        "east" => AxisDirection::East,
+                        "west" => AxisDirection::West,
+                        "up" => AxisDirection::Up,
+                        "down" => AxisDirection::Down,
+                        _ => AxisDirection::Other(s),
+                    })
+                }
+                Attribute::Keyword(_, Node::UNIT(u)) => unit = Some(u),
+                Attribute::Keyword(_, Node::MERIDIAN(m)) => meridian = Some(m),
+                Attribute::Keyword(_, Node::ORDER(n)) => order = Some(n),
+                _ => (),
+            }
+        }
+
+        // The meridian takes precedence over the named direction
+        if let Some(m) = meridian {
+            direction = direction.and(Some(AxisDirection::Meridian(m)));
+        }
+
+        Ok(Axis {
+            name: name.ok_or(Error::Wkt("Missing AXIS name".into()))?,
+            direction: direction.ok_or(Error::Wkt("Missing AXIS direction".into()))?,
             unit,
+            order,
+        })
+    }
+
+    fn order<'a>(&self, mut attrs: impl Iterator<Item = Attribute<'a, Node<'a>>>) -> Result<u8> {
+        match attrs.next() {
+            Some(Attribute::Number(s)) => s
+                .parse()
+                .map_err(|_| Error::Wkt(format!("Invalid ORDER value: {s}").into())),
+            _ => Err(Error::Wkt("Invalid ORDER".into())),
+        }
+    }
+
+    // Return the meridian angle in degrees
+    fn meridian<'a>(&self, attrs: impl Iterator<Item = Attribute<'a, Node<'a>>>) -> Result<f64> {
+        let mut value = None;
+        let mut unit = None;
+
+        for (i, a) in attrs.enumerate() {
+            match a {
+                Attribute::Number(s) if i == 0 => value = Some(parse_number(s)?),
+                Attribute::Keyword(_, Node::UNIT(u)) => unit = Some(u),
+                _ => (),
+            }
+        }
+
+        let value = value.ok_or(Error::Wkt("Missing MERIDIAN value".into()))?;
+        Ok(match unit {
+            Some(u) if !u.is_degree() => (value * u.factor).to_degrees(),
+            _ => value,
         })
     }
 
@@ -170,16 +638,40 @@ impl Builder {
             }
         }
 
+        self.dedup_parameters(&mut parameters);
+
         Ok(Projection {
             name: name.unwrap_or(""),
-            method: method.ok_or(Error::Wkt("Missing METHOD in projection definition".into()))?,
+            method: method.ok_or(Error::Wkt("projection has no METHOD".into()))?,
             parameters,
             authority,
         })
     }
 
+    // Remove repeated parameters: the last occurrence wins.
+    // Only identical names are considered, aliases are
+    // checked when formatting.
+    fn dedup_parameters(&self, parameters: &mut Vec<Parameter>) {
+        let mut i = 0;
+        while i < parameters.len() {
+            if parameters[i + 1..]
+                .iter()
+                .any(|p| p.name == parameters[i].name)
+            {
+                let p = parameters.remove(i);
+                self.diagnostics.borrow_mut().warn(format!(
+                    "Duplicate parameter '{}', value {} was overridden",
+                    p.name, p.value
+                ));
+            } else {
+                i += 1;
+            }
+        }
+    }
+
     fn method<'a>(
         &self,
+        key: &str,
         attrs: impl Iterator<Item = Attribute<'a, Node<'a>>>,
     ) -> Result<Method<'a>> {
         let mut name = None;
@@ -194,7 +686,7 @@ impl Builder {
         }
 
         Ok(Method {
-            name: name.ok_or(Error::Wkt("Missing METHOD or PROJECTION name".into()))?,
+            name: name.ok_or_else(|| Error::Wkt(format!("Missing {key} name").into()))?,
             authority,
         })
     }
@@ -231,26 +723,83 @@ impl Builder {
 
     fn geogcs<'a>(
         &self,
+        geocentric: bool,
         attrs: impl Iterator<Item = Attribute<'a, Node<'a>>>,
     ) -> Result<Geogcs<'a>> {
         let mut name = None;
         let mut datum = None;
         let mut unit = None;
+        let mut prime_meridian = None;
+        let mut cs = None;
+        let mut usage = None;
+        let mut remark = None;
+        let mut scope = None;
+        let mut area = None;
+        let mut bounding_box = None;
+        let mut authority = None;
+        let mut axis_units = vec![];
 
         for (i, a) in attrs.enumerate() {
             match a {
                 Attribute::Quoted(s) if i == 0 => name = Some(s),
                 Attribute::Keyword(_, n) => match n {
                     Node::DATUM(d) => datum = Some(d),
+                    Node::AUTHORITY(auth) => authority = Some(auth),
+                    // Base geodetic crs
+                    Node::GEOGCRS(base) => {
+                        datum = datum.or(Some(base.datum));
+                        prime_meridian = prime_meridian.or(base.prime_meridian);
+                    }
                     Node::UNIT(u) => unit = Some(u),
+                    Node::PRIMEM(pm) => prime_meridian = Some(pm),
+                    Node::CS(c) => cs = Some(c),
+                    // WKT2: unit defined in axis
+                    Node::AXIS(ax) => axis_units.extend(ax.unit),
+                    Node::USAGE(u) => usage = usage.or(Some(u)),
+                    Node::REMARK(s) => remark = Some(s),
+                    // WKT2 2015: usage is defined at the crs level
+                    Node::SCOPE(s) => scope = Some(s),
+                    Node::AREA(s) => area = Some(s),
+                    Node::BBOX(b) => bounding_box = Some(b),
                     _ => (),
                 },
                 _ => (),
             }
         }
 
+        let geocentric = geocentric || matches!(&cs, Some(c) if c.cs_type == CsType::Cartesian);
+
+        if unit.is_none() {
+            // Ellipsoidal 3D crs have both angular and linear axes
+            let unit_type = if geocentric {
+                UnitType::Linear
+            } else {
+                UnitType::Angular
+            };
+            unit = axis_units.into_iter().find(|u| u.unit_type == unit_type);
+        }
+
+        if self.lenient_units && !geocentric {
+            if let Some(u) = unit.as_ref().filter(|u| is_linear_unit(u)) {
+                self.diagnostics.borrow_mut().warn(format!(
+                    "Linear unit '{}' for geographic crs, assuming degree",
+                    u.name
+                ));
+                unit = None;
+            }
+        }
+
         if let Some(u) = unit.as_mut() {
             match u.unit_type {
+                // Geocentric crs unit should be linear
+                UnitType::Unknown | UnitType::Linear if geocentric => {
+                    u.unit_type = UnitType::Linear
+                }
+                UnitType::Angular if geocentric => {
+                    return Err(Error::Wkt(
+                        "Expecting linear unit for geocentric crs".into(),
+                    ));
+                }
                 // Geogcs unit should be angular
                 UnitType::Unknown => u.unit_type = UnitType::Angular,
                 UnitType::Linear => {
@@ -265,20 +814,173 @@ impl Builder {
             name: name.unwrap_or(""),
             datum: datum.ok_or(Error::Wkt("Missing DATUM for geodetic crs".into()))?,
             unit,
+            prime_meridian,
+            cs,
+            usage: usage.or_else(|| usage_2015(scope, area, bounding_box)),
+            authority,
+            remark,
+        })
+    }
+
+    fn usage<'a>(
+        &self,
+        attrs: impl Iterator<Item = Attribute<'a, Node<'a>>>,
+    ) -> Result<UsageInfo<'a>> {
+        let mut scope = None;
+        let mut area = None;
+        let mut bounding_box = None;
+
+        for a in attrs {
+            match a {
+                Attribute::Keyword(_, Node::SCOPE(s)) => scope = Some(s),
+                Attribute::Keyword(_, Node::AREA(s)) => area = Some(s),
+                Attribute::Keyword(_, Node::BBOX(b)) => bounding_box = Some(b),
+                _ => (),
+            }
+        }
+
+        Ok(UsageInfo {
+            scope,
+            area,
+            bounding_box,
+        })
+    }
+
+    fn text<'a>(
+        &self,
+        key: &'a str,
+        attrs: impl Iterator<Item = Attribute<'a, Node<'a>>>,
+    ) -> Result<&'a str> {
+        let mut text = None;
+
+        for (i, a) in attrs.enumerate() {
+            if i == 0 {
+                text = a.as_quoted();
+            }
+        }
+
+        text.ok_or_else(|| Error::Wkt(format!("Missing {key} text").into()))
+    }
+
+    fn bbox<'a>(&self, attrs: impl Iterator<Item = Attribute<'a, Node<'a>>>) -> Result<[f64; 4]> {
+        let mut bbox = [0.0; 4];
+        let mut count = 0;
+
+        for a in attrs {
+            match a {
+                Attribute::Number(s) if count < 4 => {
+                    bbox[count] = parse_number(s)?;
+                    count += 1;
+                }
+                _ => return Err(Error::Wkt("Invalid BBOX".into())),
+            }
+        }
+
+        if count != 4 {
+            return Err(Error::Wkt("Wrong number of values for BBOX".into()));
+        }
+
+        Ok(bbox)
+    }
+
+    fn coordinate_system<'a>(
+        &self,
+        attrs: impl Iterator<Item = Attribute<'a, Node<'a>>>,
+    ) -> Result<CoordinateSystem> {
+        let mut cs_type = None;
+        let mut dimension = None;
+
+        for (i, a) in attrs.enumerate() {
+            match a {
+                Attribute::Label(s) if i == 0 => {
+                    cs_type = Some(match s.to_ascii_lowercase().as_str() {
+                        "cartesian" => CsType::Cartesian,
+                        "ellipsoidal" => CsType::Ellipsoidal,
+                        "vertical" => CsType::Vertical,
+                        "temporal" | "temporalcount" | "temporalmeasure" | "temporaldatetime" => {
+                            CsType::Temporal
+                        }
+                        "parametric" => CsType::Parametric,
+                        "ordinal" => CsType::Ordinal,
+                        _ => CsType::Unknown,
+                    })
+                }
+                Attribute::Number(s) if i == 1 => {
+                    dimension = Some(
+                        s.parse()
+                            .ok()
+                            .filter(|d| (1..=3).contains(d))
+                            .ok_or_else(|| {
+                                Error::Wkt(format!("Invalid CS dimension: {s}").into())
+                            })?,
+                    )
+                }
+                _ => (),
+            }
+        }
+
+        Ok(CoordinateSystem {
+            cs_type: cs_type.ok_or(Error::Wkt("Missing CS type".into()))?,
+            dimension: dimension.ok_or(Error::Wkt("Missing CS dimension".into()))?,
+        })
+    }
+
+    fn prime_meridian<'a>(
+        &self,
+        key: &str,
+        attrs: impl Iterator<Item = Attribute<'a, Node<'a>>>,
+    ) -> Result<PrimeMeridian<'a>> {
+        let mut name = None;
+        let mut longitude = None;
+        let mut unit = None;
+
+        for (i, a) in attrs.enumerate() {
+            match a {
+                Attribute::Quoted(s) if i == 0 => name = Some(s),
+                Attribute::Number(s) if i == 1 => longitude = Some(parse_number(s)?),
+                Attribute::Keyword(_, Node::UNIT(u)) => unit = Some(u),
+                _ => (),
+            }
+        }
+
+        if let Some(u) = unit.as_mut() {
+            // Prime meridian unit should be angular
+            if u.unit_type == UnitType::Unknown {
+                u.unit_type = UnitType::Angular;
+            }
+        }
+
+        Ok(PrimeMeridian {
+            name: name.ok_or_else(|| Error::Wkt(format!("Missing {key} name").into()))?,
+            longitude: longitude
+                .ok_or_else(|| Error::Wkt(format!("Missing {key} longitude").into()))?,
+            unit,
         })
     }
 
-    fn datum<'a>(&self, attrs: impl Iterator<Item = Attribute<'a, Node<'a>>>) -> Result<Datum<'a>> {
+    fn datum<'a>(
+        &self,
+        key: &str,
+        attrs: impl Iterator<Item = Attribute<'a, Node<'a>>>,
+    ) -> Result<Datum<'a>> {
         let mut name = None;
         let mut ellipsoid = None;
         let mut to_wgs84 = vec![];
+        let mut has_explicit_towgs84 = false;
+        let mut nadgrids = None;
+        let mut authority = None;
 
         for (i, a) in attrs.enumerate() {
             match a {
                 Attribute::Quoted(s) if i == 0 => name = Some(s),
                 Attribute::Keyword(_, n) => match n {
                     Node::ELLIPSOID(e) => ellipsoid = Some(e),
-                    Node::TOWGS84(v) => to_wgs84 = v,
+                    Node::TOWGS84(v) => {
+                        to_wgs84 = v;
+                        has_explicit_towgs84 = true;
+                    }
+                    Node::NADGRIDS(s) => nadgrids = Some(s),
+                    Node::AUTHORITY(auth) => authority = Some(auth),
                     _ => (),
                 },
                 _ => (),
@@ -287,13 +989,51 @@ impl Builder {
 
         Ok(Datum {
             name: name.unwrap_or("Unknown"),
-            ellipsoid: ellipsoid.ok_or(Error::Wkt("Missing ellipsoid for DATUM".into()))?,
-            to_wgs84,
+            ellipsoid: ellipsoid
+                .ok_or_else(|| Error::Wkt(format!("Missing ellipsoid for {key}").into()))?,
+            to_wgs84: to_wgs84.into(),
+            has_explicit_towgs84,
+            nadgrids,
+            authority,
+        })
+    }
+
+    // Datum ensemble: geodetic ensembles are handled as a datum,
+    // vertical ensembles have no ellipsoid and are ignored
+    fn ensemble<'a>(
+        &self,
+        key: &'a str,
+        attrs: impl Iterator<Item = Attribute<'a, Node<'a>>>,
+    ) -> Result<Node<'a>> {
+        let mut name = None;
+        let mut ellipsoid = None;
+        let mut authority = None;
+
+        for (i, a) in attrs.enumerate() {
+            match a {
+                Attribute::Quoted(s) if i == 0 => name = Some(s),
+                Attribute::Keyword(_, Node::ELLIPSOID(e)) => ellipsoid = Some(e),
+                Attribute::Keyword(_, Node::AUTHORITY(auth)) => authority = Some(auth),
+                _ => (),
+            }
+        }
+
+        Ok(match ellipsoid {
+            Some(ellipsoid) => Node::DATUM(Datum {
+                name: name.unwrap_or("Unknown"),
+                ellipsoid,
+                to_wgs84: vec![].into(),
+                has_explicit_towgs84: false,
+                nadgrids: None,
+                authority,
+            }),
+            None => Node::OTHER(key),
         })
     }
 
     fn authority<'a>(
         &self,
+        key: &str,
         attrs: impl Iterator<Item = Attribute<'a, Node<'a>>>,
     ) -> Result<Authority<'a>> {
         let mut name = None;
@@ -308,8 +1048,8 @@ impl Builder {
         }
 
         Ok(Authority {
-            name: name.ok_or(Error::Wkt("Missing AUTHORITY name".into()))?,
-            code: code.ok_or(Error::Wkt("Missing AUTHORITY code".into()))?,
+            name: name.ok_or_else(|| Error::Wkt(format!("Missing {key} name").into()))?,
+            code: code.ok_or_else(|| Error::Wkt(format!("Missing {key} code").into()))?,
         })
     }
 
@@ -320,13 +1060,13 @@ impl Builder {
     ) -> Result<Unit<'a>> {
         let mut name = None;
         let mut factor = None;
-        let mut _authority = None;
+        let mut authority = None;
 
         for (i, a) in attrs.enumerate() {
             match a {
                 Attribute::Quoted(s) if i == 0 => name = Some(s),
                 Attribute::Number(s) if i == 1 => factor = Some(parse_number(s)?),
-                Attribute::Keyword(_, Node::AUTHORITY(auth)) => _authority = Some(auth),
+                Attribute::Keyword(_, Node::AUTHORITY(auth)) => authority = Some(auth),
                 _ => (),
             }
         }
@@ -335,29 +1075,37 @@ impl Builder {
             name: name.ok_or(Error::Wkt("Missing UNIT name".into()))?,
             factor: factor.ok_or(Error::Wkt("Missing UNIT factor".into()))?,
             unit_type: match key {
-                "ANGLEUNIT" => UnitType::Angular,
-                "SCALUNIT" => UnitType::Scale,
+                "ANGLEUNIT" | "ANGULARUNIT" => UnitType::Angular,
+                "SCALEUNIT" => UnitType::Scale,
                 "LENGTHUNIT" => UnitType::Linear,
                 _ => UnitType::Unknown,
             },
+            authority,
         })
     }
 
     fn compoundcrs<'a>(
         &self,
+        key: &str,
         attrs: impl Iterator<Item = Attribute<'a, Node<'a>>>,
     ) -> Result<Compoundcrs<'a>> {
         let mut name = None;
         let mut h_crs = None;
         let mut v_crs = None;
+        let mut usage = None;
+        let mut authority = None;
+        let mut remark = None;
 
         for (i, a) in attrs.enumerate() {
             match a {
                 Attribute::Quoted(s) if i == 0 => name = Some(s),
                 Attribute::Keyword(_, n) => match n {
-                    Node::PROJCRS(cs) => h_crs = Some(Horizontalcrs::Projcs(cs)),
+                    Node::AUTHORITY(auth) => authority = Some(auth),
+                    Node::PROJCRS(cs) => h_crs = Some(Horizontalcrs::Projcs(*cs)),
                     Node::GEOGCRS(cs) => h_crs = Some(Horizontalcrs::Geogcs(cs)),
                     Node::VERTICALCRS(cs) => v_crs = Some(cs),
+                    Node::USAGE(u) => usage = usage.or(Some(u)),
+                    Node::REMARK(s) => remark = Some(s),
                     _ => (),
                 },
                 _ => (),
@@ -365,40 +1113,148 @@ impl Builder {
         }
 
         Ok(Compoundcrs {
-            name: name.ok_or(Error::Wkt("Missing Compound Crs name".into()))?,
-            h_crs: h_crs.ok_or(Error::Wkt(
-                "Missing Horzontal CRS for compound crs name".into(),
-            ))?,
-            v_crs: v_crs.ok_or(Error::Wkt("Missing Vertical crs for compound".into()))?,
+            name: name.ok_or_else(|| Error::Wkt(format!("Missing {key} name").into()))?,
+            h_crs: h_crs
+                .ok_or_else(|| Error::Wkt(format!("Missing horizontal crs for {key}").into()))?,
+            v_crs: v_crs
+                .ok_or_else(|| Error::Wkt(format!("Missing vertical crs for {key}").into()))?,
+            usage,
+            authority,
+            remark,
+        })
+    }
+
+    fn coordinate_metadata<'a>(
+        &self,
+        attrs: impl Iterator<Item = Attribute<'a, Node<'a>>>,
+    ) -> Result<CoordinateMetadata<'a>> {
+        let mut crs = None;
+        let mut epoch = None;
+
+        for a in attrs {
+            if let Attribute::Keyword(_, n) = a {
+                match n {
+                    Node::EPOCH(v) => epoch = Some(v),
+                    Node::PROJCRS(_)
+                    | Node::GEOGCRS(_)
+                    | Node::GEOCCRS(_)
+                    | Node::COMPOUNDCRS(_)
+                    | Node::VERTICALCRS(_) => crs = Some(Box::new(n)),
+                    _ => (),
+                }
+            }
+        }
+
+        Ok(CoordinateMetadata {
+            crs: crs.ok_or(Error::Wkt("Missing CRS for coordinate metadata".into()))?,
+            epoch,
+        })
+    }
+
+    fn derived_projcrs<'a>(
+        &self,
+        key: &str,
+        attrs: impl Iterator<Item = Attribute<'a, Node<'a>>>,
+    ) -> Result<DerivedProjcrs<'a>> {
+        let mut name = None;
+        let mut base = None;
+
+        for (i, a) in attrs.enumerate() {
+            match a {
+                Attribute::Quoted(s) if i == 0 => name = Some(s),
+                Attribute::Keyword(_, n @ Node::PROJCRS(_)) => base = Some(Box::new(n)),
+                _ => (),
+            }
+        }
+
+        Ok(DerivedProjcrs {
+            name: name.unwrap_or(""),
+            base: base.ok_or_else(|| {
+                Error::Wkt(format!("Missing base projected crs for {key}").into())
+            })?,
         })
     }
 
+    fn epoch<'a>(&self, mut attrs: impl Iterator<Item = Attribute<'a, Node<'a>>>) -> Result<f64> {
+        match attrs.next() {
+            Some(Attribute::Number(s)) => parse_number(s),
+            _ => Err(Error::Wkt("Invalid EPOCH".into())),
+        }
+    }
+
     fn verticalcrs<'a>(
         &self,
         attrs: impl Iterator<Item = Attribute<'a, Node<'a>>>,
     ) -> Result<Verticalcrs<'a>> {
         let mut name = None;
+        let mut geoid_model = None;
+        let mut remark = None;
+        let mut unit = None;
+        let mut authority = None;
 
         for (i, a) in attrs.enumerate() {
             match a {
                 Attribute::Quoted(s) if i == 0 => name = Some(s),
+                Attribute::Keyword(_, n) => match n {
+                    Node::GEOIDMODEL(g) => geoid_model = Some(g),
+                    Node::REMARK(s) => remark = Some(s),
+                    Node::AUTHORITY(auth) => authority = Some(auth),
+                    Node::UNIT(u) => unit = Some(u),
+                    // WKT2: unit defined in axis
+                    Node::AXIS(axis) => unit = unit.or(axis.unit),
+                    _ => (),
+                },
                 _ => (),
             }
         }
 
+        if let Some(u) = unit.as_mut() {
+            // Vertical crs unit should be linear
+            if u.unit_type == UnitType::Unknown {
+                u.unit_type = UnitType::Linear;
+            }
+        }
+
         Ok(Verticalcrs {
             name: name.unwrap_or(""),
+            geoid_model,
+            unit,
+            authority,
+            remark,
+        })
+    }
+
+    fn geoid_model<'a>(
+        &self,
+        attrs: impl Iterator<Item = Attribute<'a, Node<'a>>>,
+    ) -> Result<GeoidModel<'a>> {
+        let mut name = None;
+        let mut authority = None;
+
+        for (i, a) in attrs.enumerate() {
+            match a {
+                Attribute::Quoted(s) if i == 0 => name = Some(s),
+                Attribute::Keyword(_, Node::AUTHORITY(auth)) => authority = Some(auth),
+                _ => (),
+            }
+        }
+
+        Ok(GeoidModel {
+            name: name.ok_or(Error::Wkt("Missing GEOIDMODEL name".into()))?,
+            authority,
         })
     }
 
     fn ellipsoid<'a>(
         &self,
+        key: &str,
         attrs: impl Iterator<Item = Attribute<'a, Node<'a>>>,
     ) -> Result<Ellipsoid<'a>> {
         let mut name = None;
         let mut semi_major = None;
         let mut rf = None;
         let mut unit = None;
+        let mut authority = None;
 
         for (i, a) in attrs.enumerate() {
             match a {
@@ -406,15 +1262,25 @@ impl Builder {
                 Attribute::Number(s) if i == 1 => semi_major = Some(s),
                 Attribute::Number(s) if i == 2 => rf = Some(s),
                 Attribute::Keyword(_, Node::UNIT(u)) => unit = Some(u),
+                Attribute::Keyword(_, Node::AUTHORITY(auth)) => authority = Some(auth),
                 _ => (),
             }
         }
 
+        if let Some(u) = unit.as_mut() {
+            // Ellipsoid unit should be linear
+            if u.unit_type == UnitType::Unknown {
+                u.unit_type = UnitType::Linear;
+            }
+        }
+
         Ok(Ellipsoid {
-            name: name.ok_or(Error::Wkt("Missing AUTHORITY name".into()))?,
-            a: semi_major.ok_or(Error::Wkt("Invalid ELLIPSOID semi-major axis".into()))?,
-            rf: rf.ok_or(Error::Wkt("Invalid ELLIPSOID inverse flattening".into()))?,
+            name: name.ok_or_else(|| Error::Wkt(format!("Missing {key} name").into()))?,
+            a: semi_major
+                .ok_or_else(|| Error::Wkt(format!("Invalid {key} semi-major axis").into()))?,
+            rf: rf.ok_or_else(|| Error::Wkt(format!("Invalid {key} inverse flattening").into()))?,
             unit,
+            authority,
         })
     }
 
@@ -424,16 +1290,23 @@ impl Builder {
     ) -> Result<Vec<&'a str>> {
         let mut to_wgs84 = vec![];
 
-        for a in attrs {
+        for (i, a) in attrs.enumerate() {
             match a {
-                Attribute::Number(s) => to_wgs84.push(s),
+                Attribute::Number(s) => match parse_number(s) {
+                    Ok(v) if v.is_finite() => to_wgs84.push(s),
+                    _ => {
+                        return Err(Error::Wkt(
+                            format!("invalid TOWGS84 parameter at position {i}").into(),
+                        ));
+                    }
+                },
                 _ => {
                     return Err(Error::Wkt(format!("Expecting number not {a:?}").into()));
                 }
             }
         }
 
-        if !matches!(to_wgs84.len(), 0 | 3 | 7) {
+        if !matches!(to_wgs84.len(), 3 | 7) {
             return Err(Error::Wkt("Wrong number of parameters for TOWGS84".into()));
         }
 
@@ -441,8 +1314,66 @@ impl Builder {
     }
 }
 
+// Build usage from WKT2 2015 crs level SCOPE, AREA and BBOX
+fn usage_2015<'a>(
+    scope: Option<&'a str>,
+    area: Option<&'a str>,
+    bounding_box: Option<[f64; 4]>,
+) -> Option<UsageInfo<'a>> {
+    (scope.is_some() || area.is_some() || bounding_box.is_some()).then_some(UsageInfo {
+        scope,
+        area,
+        bounding_box,
+    })
+}
+
 use crate::parse::FromStr;
 
+// Keywords expected at the root of a WKT CRS
+const ROOT_KEYWORDS: &[&str] = &[
+    "PROJCS",
+    "PROJCRS",
+    "PROJECTEDCRS",
+    "DERIVEDPROJCRS",
+    "GEOGCS",
+    "GEOGCRS",
+    "GEOGRAPHICCRS",
+    "GEOCCS",
+    "GEODCRS",
+    "GEODETICCRS",
+    "COMPD_CS",
+    "COMPOUNDCRS",
+    "VERT_CS",
+    "VERTCRS",
+    "VERTICALCRS",
+    "BOUNDCRS",
+    "COORDINATEMETADATA",
+    "ENGCRS",
+    "ENGINEERINGCRS",
+    "LOCAL_CS",
+];
+
+// Return a warning for keywords found at an unusual depth
+pub(crate) fn depth_warning(key: &str, depth: usize) -> Option<String> {
+    (depth == 0 && !ROOT_KEYWORDS.contains(&key))
+        .then(|| format!("Unexpected top-level keyword {key}"))
+}
+
+// Check for linear units, including untyped WKT1 units
+// named as metre
+fn is_linear_unit(u: &Unit) -> bool {
+    match u.unit_type {
+        UnitType::Linear => true,
+        UnitType::Unknown => match &u.authority {
+            Some(auth) if auth.name == "EPSG" => auth.code == "9001",
+            _ => ["metre", "meter", "m"]
+                .iter()
+                .any(|name| u.name.eq_ignore_ascii_case(name)),
+        },
+        _ => false,
+    }
+}
+
 pub fn parse_number(s: &str) -> Result<f64> {
     f64::from_str(s).map_err(|err| Error::Wkt(format!("Error parsing number: {err:?}").into()))
 }
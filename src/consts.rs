@@ -72,6 +72,7 @@ pub mod methods {
 
     define!(POPULAR_VISUALISATION_PSEUDO_MERCATOR,  "Popular Visualisation Pseudo Mercator",    "1024");
     define!(LAMBERT_AZIMUTHAL_EQUAL_AREA_SPHERICAL, "Lambert Azimuthal Equal Area (Spherical)", "1027");
+    define!(KROVAK_NORTH_ORIENTED,                  "Krovak (North Orientated)",                "1041");
     define!(LAMBERT_CONIC_CONFORMAL_2SP_MICHIGAN,   "Lambert Conic Conformal (2SP Michigan)",   "1051");
 
     define!(LAMBERT_CONIC_CONFORMAL_1SP,            "Lambert Conic Conformal (1SP)",            "9801");
@@ -79,16 +80,33 @@ pub mod methods {
     define!(LAMBERT_CONIC_CONFORMAL_2SP_BELGIUM,    "Lambert Conic Conformal (2SP Belgium)",    "9803");
     define!(MERCATOR_VARIANT_A,                     "Mercator (variant A)",                     "9804");
     define!(MERCATOR_VARIANT_B,                     "Mercator (variant B)",                     "9805");
+    define!(CASSINI_SOLDNER,                        "Cassini-Soldner",                          "9806");
     define!(TRANSVERSE_MERCATOR,                    "Transverse Mercator"                   ,   "9807");
     define!(TRANSVERSE_MERCATOR_SOUTH_ORIENTATED,   "Transverse Mercator (South Orientated)",   "9808");
     define!(OBLIQUE_STEREOGRAPHIC,                  "Oblique Stereographic",                    "9809");
     define!(POLAR_STEREOGRAPHIC_VARIANT_A,          "Polar Stereographic (variant A)",          "9810");
+    define!(HOTINE_OBLIQUE_MERCATOR_VARIANT_A,      "Hotine Oblique Mercator (variant A)",      "9812");
+    define!(HOTINE_OBLIQUE_MERCATOR_VARIANT_B,      "Hotine Oblique Mercator (variant B)",      "9815");
+    define!(KROVAK,                                 "Krovak",                                   "9819");
     define!(ALBERS_EQUAL_AREA,                      "Albers Equal Area",                        "9822");
     define!(LAMBERT_AZIMUTHAL_EQUAL_AREA,           "Lambert Azimuthal Equal Area",             "9820");
     define!(POLAR_STEREOGRAPHIC_VARIANT_B,          "Polar Stereographic (variant B)",          "9829");
+    define!(GUAM_PROJECTION,                        "Guam Projection",                          "9831");
+    define!(MODIFIED_AZIMUTHAL_EQUIDISTANT,         "Modified Azimuthal Equidistant",           "9832");
+    define!(LAMBERT_CYLINDRICAL_EQUAL_AREA_SPHERICAL, "Lambert Cylindrical Equal Area (Spherical)", "9834");
+    define!(LAMBERT_CYLINDRICAL_EQUAL_AREA,         "Lambert Cylindrical Equal Area",           "9835");
 
     define!(PROJ_WKT2_NAME_MOLLWEIDE,            "Mollweide",     "");
     define!(PROJ_WKT2_NAME_WAGNER_IV,            "Wagner IV",     "");
     define!(PROJ_WKT2_NAME_WAGNER_V,             "Wagner V",      "");
+    define!(PROJ_WKT2_NAME_METHOD_ROBINSON,      "Robinson",      "");
+    define!(PROJ_WKT2_NAME_METHOD_NATURAL_EARTH, "Natural Earth", "");
+    define!(PROJ_WKT2_NAME_METHOD_NATURAL_EARTH_II, "Natural Earth II", "");
     define!(PROJ_WKT2_NAME_METHOD_STEREOGRAPHIC, "Stereographic", "");
+    define!(PROJ_WKT2_NAME_METHOD_BEHRMANN,      "Behrmann",      "");
+    define!(PROJ_WKT2_NAME_METHOD_GALL_STEREOGRAPHIC, "Gall Stereographic", "");
+
+    // ESRI methods, no WKT2 definition
+    define!(ESRI_STEREOGRAPHIC_NORTH_POLE, "", "");
+    define!(ESRI_STEREOGRAPHIC_SOUTH_POLE, "", "");
 }
@@ -0,0 +1,93 @@
+//!
+//! Datum presets
+//!
+//! Well known datums identified by name, used when
+//! no TOWGS84 parameters are given.
+//!
+//! From proj library: <https://github.com/OSGeo/PROJ>  (/src/datums.cpp)
+//!
+
+pub struct DatumPreset {
+    pub wkt_name: &'static str,
+    pub proj_name: &'static str,
+    pub shift: DatumShift,
+}
+
+/// Datum shift of a preset
+pub enum DatumShift {
+    /// TOWGS84 parameters
+    ToWgs84(&'static str),
+    /// Grid files
+    Nadgrids(&'static str),
+}
+
+macro_rules! datum {
+    ($wkt_name:expr, $proj_name:expr, nadgrids = $nadgrids:expr) => {
+        DatumPreset {
+            wkt_name: $wkt_name,
+            proj_name: $proj_name,
+            shift: DatumShift::Nadgrids($nadgrids),
+        }
+    };
+    ($wkt_name:expr, $proj_name:expr, $to_wgs84:expr) => {
+        DatumPreset {
+            wkt_name: $wkt_name,
+            proj_name: $proj_name,
+            shift: DatumShift::ToWgs84($to_wgs84),
+        }
+    };
+}
+
+#[rustfmt::skip]
+pub const DATUM_PRESETS: [DatumPreset; 10] = [
+    datum!("WGS_1984",                             "WGS84",         "0,0,0,0,0,0,0"),
+    datum!("North_American_Datum_1983",            "NAD83",         "0,0,0,0,0,0,0"),
+    datum!("North_American_Datum_1927",            "NAD27",         nadgrids = "@conus,@alaska,@ntv2_0.gsb,@ntv1_can.dat"),
+    datum!("Greek_Geodetic_Reference_System_1987", "GGRS87",        "-199.87,74.79,246.62"),
+    datum!("Deutsches_Hauptdreiecksnetz",          "potsdam",       "598.1,73.7,418.2,0.202,0.045,-2.455,6.7"),
+    datum!("Carthage",                             "carthage",      "-263.0,6.0,431.0"),
+    datum!("Militar_Geographische_Institut",       "hermannskogel", "577.326,90.129,463.919,5.137,1.474,5.297,2.4232"),
+    datum!("TM65",                                 "ire65",         "482.530,-130.596,564.557,-1.042,-0.214,-0.631,8.15"),
+    datum!("OSGB_1936",                            "OSGB36",        "446.448,-125.157,542.060,0.1502,0.2470,0.8421,-20.4894"),
+    datum!("New_Zealand_Geodetic_Datum_1949",      "nzgd49",        "59.47,-5.04,187.44,0.47,-0.1,1.024,-4.5993"),
+];
+
+// Compare datum names ignoring case and
+// considering '_' and ' ' as equivalent
+fn eq_datum_name(a: &str, b: &str) -> bool {
+    fn norm(c: u8) -> u8 {
+        match c {
+            b'_' => b' ',
+            c => c.to_ascii_lowercase(),
+        }
+    }
+    a.len() == b.len() && a.bytes().zip(b.bytes()).all(|(a, b)| norm(a) == norm(b))
+}
+
+/// Retrieve a datum preset from its name
+pub fn find_datum_preset(name: &str) -> Option<&'static DatumPreset> {
+    DATUM_PRESETS
+        .iter()
+        .find(|d| eq_datum_name(d.wkt_name, name))
+}
+
+// ==============================
+//  Tests
+// ==============================
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn datum_preset_lookup() {
+        assert_eq!(
+            find_datum_preset("New_Zealand_Geodetic_Datum_1949").map(|d| d.proj_name),
+            Some("nzgd49")
+        );
+        assert_eq!(
+            find_datum_preset("new zealand geodetic datum 1949").map(|d| d.proj_name),
+            Some("nzgd49")
+        );
+        assert!(find_datum_preset("Unknown").is_none());
+    }
+}
@@ -0,0 +1,114 @@
+//!
+//! Ellipsoids
+//!
+//! Well known ellipsoids as `(proj name, semi-major axis in metre, inverse flattening)`.
+//!
+//! From proj library: <https://github.com/OSGeo/PROJ>  (/src/ellps.cpp)
+//!
+
+#[rustfmt::skip]
+pub const KNOWN_ELLIPSOIDS: &[(&str, f64, f64)] = &[
+    ("WGS84",     6378137.0,   298.257223563),
+    ("GRS80",     6378137.0,   298.257222101),
+    ("WGS72",     6378135.0,   298.26),
+    ("GRS67",     6378160.0,   298.247167427),
+    ("clrk66",    6378206.4,   294.9786982139006),
+    ("clrk80",    6378249.145, 293.4663),
+    ("clrk80ign", 6378249.2,   293.4660212936269),
+    ("intl",      6378388.0,   297.0),
+    ("bessel",    6377397.155, 299.1528128),
+    ("airy",      6377563.396, 299.3249646),
+    ("krass",     6378245.0,   298.3),
+    ("aust_SA",   6378160.0,   298.25),
+    ("helmert",   6378200.0,   298.3),
+];
+
+/// EPSG codes of the known ellipsoids as `(epsg code, proj name, wkt name)`.
+#[rustfmt::skip]
+pub const KNOWN_ELLIPSOID_CODES: &[(&str, &str, &str)] = &[
+    ("7030", "WGS84",     "WGS 84"),
+    ("7019", "GRS80",     "GRS 1980"),
+    ("7043", "WGS72",     "WGS 72"),
+    ("7036", "GRS67",     "GRS 1967"),
+    ("7008", "clrk66",    "Clarke 1866"),
+    ("7011", "clrk80ign", "Clarke 1880 (IGN)"),
+    ("7022", "intl",      "International 1924"),
+    ("7004", "bessel",    "Bessel 1841"),
+    ("7001", "airy",      "Airy 1830"),
+    ("7024", "krass",     "Krassowsky 1940"),
+    ("7003", "aust_SA",   "Australian National Spheroid"),
+    ("7020", "helmert",   "Helmert 1906"),
+];
+
+// Relative tolerance when comparing ellipsoid parameters:
+// small enough to tell WGS84 from GRS80, large enough
+// to accept rounded inverse flattenings
+const RELATIVE_TOLERANCE: f64 = 1e-9;
+
+fn approx_eq(a: f64, b: f64) -> bool {
+    (a - b).abs() <= RELATIVE_TOLERANCE * b.abs()
+}
+
+/// Retrieve the proj name of a known ellipsoid from its semi-major
+/// axis (in metre) and its inverse flattening
+pub fn find_ellipsoid(a: f64, rf: f64) -> Option<&'static str> {
+    KNOWN_ELLIPSOIDS
+        .iter()
+        .find(|(_, ka, krf)| approx_eq(a, *ka) && approx_eq(rf, *krf))
+        .map(|(name, _, _)| *name)
+}
+
+/// Retrieve the parameters of a known ellipsoid from its proj name
+pub fn find_ellipsoid_by_name(name: &str) -> Option<(f64, f64)> {
+    KNOWN_ELLIPSOIDS
+        .iter()
+        .find(|(n, _, _)| *n == name)
+        .map(|(_, a, rf)| (*a, *rf))
+}
+
+/// Retrieve the proj name of a known ellipsoid from its EPSG code
+///
+/// The semi-major axis (in metre) and the inverse flattening
+/// must match the parameters of the known ellipsoid.
+pub fn find_ellipsoid_by_code(code: &str, a: f64, rf: f64) -> Option<&'static str> {
+    KNOWN_ELLIPSOID_CODES
+        .iter()
+        .find(|(c, _, _)| *c == code)
+        .and_then(|(_, name, _)| {
+            find_ellipsoid_by_name(name)
+                .filter(|(ka, krf)| approx_eq(a, *ka) && approx_eq(rf, *krf))
+                .map(|_| *name)
+        })
+}
+
+// ==============================
+//  Tests
+// ==============================
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ellipsoid_lookup() {
+        assert_eq!(find_ellipsoid(6378137.0, 298.257222101), Some("GRS80"));
+        assert_eq!(find_ellipsoid(6378137.0, 298.2572221008827), Some("GRS80"));
+        assert_eq!(find_ellipsoid(6378137.0, 298.257223563), Some("WGS84"));
+        assert_eq!(find_ellipsoid(6378206.4, 294.978698213898), Some("clrk66"));
+        assert!(find_ellipsoid(6378137.0, 298.2572).is_none());
+        assert_eq!(find_ellipsoid_by_name("intl"), Some((6378388.0, 297.0)));
+        assert_eq!(
+            find_ellipsoid_by_code("7008", 6378206.4, 294.9786982138982),
+            Some("clrk66")
+        );
+        assert!(find_ellipsoid_by_code("7008", 6378206.4, 294.97869).is_none());
+        assert!(find_ellipsoid_by_code("7008", 6378137.0, 298.257223563).is_none());
+        assert!(find_ellipsoid_by_code("7999", 6378137.0, 298.257223563).is_none());
+    }
+
+    #[test]
+    fn ellipsoid_codes_are_known() {
+        for (_, name, _) in KNOWN_ELLIPSOID_CODES {
+            assert!(find_ellipsoid_by_name(name).is_some(), "{name}");
+        }
+    }
+}
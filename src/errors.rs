@@ -11,8 +11,93 @@ pub enum Error {
     Wkt(Cow<'static, str>),
     #[error("JS parse error")]
     JsParse,
+    #[error("Invalid UTF-8 input: {0}")]
+    Utf8(#[from] std::str::Utf8Error),
     #[error("Format error")]
     Fmt(#[from] std::io::Error),
+    #[error("WKT error: {context}")]
+    Context {
+        context: Cow<'static, str>,
+        #[source]
+        source: Box<dyn std::error::Error + Send + Sync>,
+    },
+}
+
+impl Error {
+    /// Wrap `source` with a context message
+    pub(crate) fn context(context: impl Into<Cow<'static, str>>, source: Error) -> Self {
+        Self::Context {
+            context: context.into(),
+            source: Box::new(source),
+        }
+    }
+
+    /// Return the innermost error of the chain of sources
+    ///
+    /// This is the error itself if it has no source.
+    pub fn root_cause(&self) -> &(dyn std::error::Error + 'static) {
+        let mut err: &(dyn std::error::Error + 'static) = self;
+        while let Some(source) = err.source() {
+            err = source;
+        }
+        err
+    }
+}
+
+impl From<Error> for std::io::Error {
+    fn from(err: Error) -> Self {
+        match err {
+            Error::Fmt(err) => err,
+            err => Self::new(std::io::ErrorKind::InvalidData, err),
+        }
+    }
 }
 
 pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+// ==============================
+//  Tests
+// ==============================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::error::Error as _;
+    use std::io;
+
+    #[test]
+    fn convert_to_io_error() {
+        let err = io::Error::from(Error::Parse);
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+
+        let err = io::Error::from(Error::Wkt("Missing DATUM".into()));
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        assert_eq!(err.to_string(), "WKT error: Missing DATUM");
+
+        let err = io::Error::from(Error::Fmt(io::Error::new(
+            io::ErrorKind::WriteZero,
+            "failed to write",
+        )));
+        assert_eq!(err.kind(), io::ErrorKind::WriteZero);
+        assert_eq!(err.to_string(), "failed to write");
+    }
+
+    #[test]
+    fn error_source() {
+        let err = Error::Fmt(io::Error::new(io::ErrorKind::WriteZero, "failed to write"));
+        assert_eq!(
+            err.source().map(|e| e.to_string()).as_deref(),
+            Some("failed to write")
+        );
+        assert!(Error::Parse.source().is_none());
+    }
+
+    #[test]
+    fn error_root_cause() {
+        let err = Error::context(
+            "Invalid GEOGCS",
+            Error::context("Invalid DATUM", Error::Wkt("Missing ellipsoid".into())),
+        );
+        assert_eq!(err.root_cause().to_string(), "WKT error: Missing ellipsoid");
+        assert_eq!(Error::Parse.root_cause().to_string(), "WKT parse error");
+    }
+}
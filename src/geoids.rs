@@ -0,0 +1,42 @@
+//!
+//! Geoid grids
+//!
+//! Well known vertical crs as `(EPSG code, geoid grid)`.
+//!
+//! Grid names are from the PROJ-data package: <https://github.com/OSGeo/PROJ-data>
+//!
+
+#[rustfmt::skip]
+pub const KNOWN_GEOID_GRIDS: &[(&str, &str)] = &[
+    // EGM96 height
+    ("5773", "us_nga_egm96_15.tif"),
+    // EGM2008 height
+    ("3855", "us_nga_egm08_25.tif"),
+    // NAVD88 height
+    ("5703", "us_noaa_g2018u0.tif"),
+];
+
+// Well known geoid models as `(model name, geoid grid)`
+#[rustfmt::skip]
+const KNOWN_GEOID_MODELS: &[(&str, &str)] = &[
+    ("GEOID12B", "us_noaa_g2012bu0.tif"),
+    ("GEOID18",  "us_noaa_g2018u0.tif"),
+    ("EGM96",    "us_nga_egm96_15.tif"),
+    ("EGM2008",  "us_nga_egm08_25.tif"),
+];
+
+/// Retrieve the geoid grid of a geoid model from its name
+pub fn find_geoid_model_grid(name: &str) -> Option<&'static str> {
+    KNOWN_GEOID_MODELS
+        .iter()
+        .find(|(n, _)| n.eq_ignore_ascii_case(name))
+        .map(|(_, grid)| *grid)
+}
+
+/// Retrieve the geoid grid of a vertical crs from its EPSG code
+pub fn find_geoid_grid(code: &str) -> Option<&'static str> {
+    KNOWN_GEOID_GRIDS
+        .iter()
+        .find(|(c, _)| *c == code)
+        .map(|(_, grid)| *grid)
+}
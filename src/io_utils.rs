@@ -0,0 +1,137 @@
+//!
+//! I/O utilities
+//!
+use crate::errors::{Error, Result};
+use crate::parser::BracketScanner;
+use std::io::{self, BufRead, Write};
+
+/// A [`Write`] adapter that appends UTF-8 data to a `String`
+///
+/// Writing invalid UTF-8 sequences returns an [`io::Error`]
+/// of kind [`io::ErrorKind::InvalidData`].
+pub struct Utf8Writer<'a>(pub &'a mut String);
+
+impl Write for Utf8Writer<'_> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let s = std::str::from_utf8(buf)
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+        self.0.push_str(s);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Iterator over the concatenated WKT objects of a reader
+///
+/// Objects are delimited by balanced brackets, whitespaces
+/// between objects are skipped. The content of the objects is
+/// not validated.
+pub(crate) struct WktObjects<R> {
+    reader: R,
+    done: bool,
+}
+
+impl<R: BufRead> WktObjects<R> {
+    pub(crate) fn new(reader: R) -> Self {
+        Self {
+            reader,
+            done: false,
+        }
+    }
+}
+
+impl<R: BufRead> Iterator for WktObjects<R> {
+    type Item = Result<String>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let mut buf = vec![];
+        let mut scanner = BracketScanner::default();
+        loop {
+            let available = match self.reader.fill_buf() {
+                Ok(b) => b,
+                Err(err) if err.kind() == io::ErrorKind::Interrupted => continue,
+                Err(err) => {
+                    self.done = true;
+                    return Some(Err(err.into()));
+                }
+            };
+            if available.is_empty() {
+                // Unterminated object
+                self.done = true;
+                return (!buf.is_empty()).then_some(Err(Error::Parse));
+            }
+
+            let mut consumed = available.len();
+            let mut complete = false;
+            for (i, &c) in available.iter().enumerate() {
+                if buf.is_empty() && c.is_ascii_whitespace() {
+                    continue;
+                }
+                buf.push(c);
+                if scanner.scan(c) {
+                    consumed = i + 1;
+                    complete = true;
+                    break;
+                }
+            }
+            self.reader.consume(consumed);
+
+            if complete {
+                return Some(
+                    String::from_utf8(buf)
+                        .map(|s| match s.strip_prefix('\u{feff}') {
+                            Some(s) => s.into(),
+                            None => s,
+                        })
+                        .map_err(|err| err.utf8_error().into()),
+                );
+            }
+        }
+    }
+}
+
+// ==============================
+//  Tests
+// ==============================
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn write_utf8() {
+        let mut buf = String::new();
+        let proj = "longlat";
+        write!(Utf8Writer(&mut buf), "+proj={proj}").unwrap();
+        assert_eq!(buf, "+proj=longlat");
+    }
+
+    #[test]
+    fn write_invalid_utf8() {
+        let mut buf = String::new();
+        let err = Utf8Writer(&mut buf).write(&[0xff, 0xfe]).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        assert!(buf.is_empty());
+    }
+
+    #[test]
+    fn split_wkt_objects() {
+        // Small buffer capacity to split objects across reads
+        let input = "\u{feff}FOO[\"a]\"\"[\",BAR[1]]\n\n  BAZ[2] QUX[";
+        let reader = io::BufReader::with_capacity(4, input.as_bytes());
+        let objects: Vec<_> = WktObjects::new(reader).collect();
+
+        assert_eq!(objects.len(), 3);
+        assert_eq!(objects[0].as_deref().unwrap(), "FOO[\"a]\"\"[\",BAR[1]]");
+        assert_eq!(objects[1].as_deref().unwrap(), "BAZ[2]");
+        assert!(matches!(objects[2], Err(Error::Parse)));
+
+        assert_eq!(WktObjects::new(" \n".as_bytes()).count(), 0);
+    }
+}
@@ -42,17 +42,34 @@
 //!
 mod builder;
 mod consts;
+mod datums;
+mod ellipsoids;
 mod errors;
+mod geoids;
+mod io_utils;
 mod methods;
 mod model;
 mod params;
 mod parse;
+mod prime_meridians;
 mod projstr;
+mod projstr_parser;
+mod report;
+mod wkt_emit;
 
 pub mod parser;
 
-pub use builder::Builder;
-pub use projstr::Formatter;
+pub use builder::{Builder, Node, OwnedNode};
+pub use errors::Error;
+pub use io_utils::Utf8Writer;
+pub use methods::{
+    find_method_by_wkt1_name, find_method_by_wkt2_name, method_mappings, supported_methods,
+    MethodMapping, ProjectionProperty,
+};
+pub use model::{CrsKind, Datum, Ellipsoid, Parameter, UnitType};
+pub use params::ParamMapping;
+pub use projstr::{ConversionOptions, Formatter, ProjParams, SPHERICAL_PROJECTIONS};
+pub use report::{Diagnostics, MatchKind, Report};
 
 use errors::Result;
 
@@ -61,10 +78,221 @@ pub fn wkt_to_projstring(i: &str) -> Result<String> {
     let mut buf = String::new();
     Builder::new()
         .parse(i)
-        .and_then(|node| Formatter::new(unsafe { buf.as_mut_vec() }).format(&node))
+        .and_then(|node| Formatter::new_string(&mut buf).format(&node))
         .and(Ok(buf))
 }
 
+/// Convert a wkt string to a projstring with the
+/// given [`ConversionOptions`]
+pub fn wkt_to_projstring_with_options(i: &str, options: ConversionOptions) -> Result<String> {
+    let mut buf = String::new();
+    Builder::new()
+        .parse(i)
+        .and_then(|node| {
+            Formatter::new_string(&mut buf)
+                .options(options)
+                .format(&node)
+        })
+        .and(Ok(buf))
+}
+
+/// Convert a wkt string to proj parameters
+/// without building the projstring
+pub fn wkt_to_proj_params(i: &str) -> Result<ProjParams<'_>> {
+    Builder::new()
+        .parse(i)
+        .and_then(|node| ProjParams::try_from(&node))
+}
+
+/// Convert a wkt string to a projstring and return
+/// a [`Report`] of the conversion
+///
+/// Warnings raised while building the WKT nodes come first
+/// in the report diagnostics.
+pub fn wkt_to_projstring_with_report(i: &str) -> Result<(String, Report)> {
+    let mut buf = String::new();
+    let (node, mut diagnostics) = Builder::new().parse_with_diagnostics(i)?;
+    let mut formatter = Formatter::new_string(&mut buf);
+    formatter.format(&node)?;
+    let mut report = formatter.into_report();
+    diagnostics.append(report.diagnostics);
+    report.diagnostics = diagnostics;
+    Ok((buf, report))
+}
+
+/// Convert a wkt string to a projstring and return
+/// the [`Diagnostics`] of the conversion
+pub fn wkt_to_projstring_with_diagnostics(i: &str) -> Result<(String, Diagnostics)> {
+    wkt_to_projstring_with_report(i).map(|(s, report)| (s, report.diagnostics))
+}
+
+/// Convert a wkt string to a projstring
+///
+/// On failure, return the error along with the warnings
+/// of the builder and the formatter collected before the
+/// failure.
+pub fn wkt_to_projstring_verbose(i: &str) -> Result<String, Vec<Error>> {
+    let into_errors = |diagnostics: Diagnostics, err: Error| -> Vec<Error> {
+        diagnostics
+            .warnings()
+            .iter()
+            .map(|w| Error::Wkt(w.clone().into()))
+            .chain([err])
+            .collect()
+    };
+
+    let (node, mut diagnostics) = match Builder::new().parse_collect(i) {
+        (Ok(node), diagnostics) => (node, diagnostics),
+        (Err(err), diagnostics) => return Err(into_errors(diagnostics, err)),
+    };
+    let mut buf = String::new();
+    let mut formatter = Formatter::new_string(&mut buf);
+    let result = formatter.format(&node);
+    diagnostics.append(formatter.into_report().diagnostics);
+    match result {
+        Ok(()) => Ok(buf),
+        Err(err) => Err(into_errors(diagnostics, err)),
+    }
+}
+
+/// Convert a proj string to a WKT1 string
+///
+/// Only a subset of projections are supported.
+pub fn projstring_to_wkt(proj: &str) -> Result<String> {
+    let mut buf = String::new();
+    projstr_parser::ProjString::parse(proj)
+        .and_then(|ps| wkt_emit::WktEmitter::new(Utf8Writer(&mut buf)).emit(&ps))
+        .and(Ok(buf))
+}
+
+/// Convert a proj string to a WKT2 string
+///
+/// Only a subset of projections are supported.
+pub fn projstring_to_wkt2(proj: &str) -> Result<String> {
+    let mut buf = String::new();
+    projstr_parser::ProjString::parse(proj)
+        .and_then(|ps| wkt_emit::WktEmitter::new(Utf8Writer(&mut buf)).emit_wkt2(&ps))
+        .and(Ok(buf))
+}
+
+/// Summary of a WKT CRS
+#[derive(Debug, Clone, PartialEq)]
+pub struct CrsInfo {
+    pub name: String,
+    pub crs_type: CrsKind,
+    /// Authority as `(name, code)`
+    pub authority: Option<(String, String)>,
+    pub datum_name: Option<String>,
+    pub ellipsoid_name: Option<String>,
+    /// `None` if the crs cannot be converted to a proj string
+    pub proj_string: Option<String>,
+}
+
+/// Parse a wkt string and return a [`CrsInfo`] summary
+///
+/// Fail only if the wkt cannot be parsed or is not a crs,
+/// a failed proj string conversion is reported as `None`.
+pub fn wkt_parse_info(i: &str) -> Result<CrsInfo> {
+    let node = Builder::new().parse(i)?;
+    let crs_type = node
+        .crs_kind()
+        .ok_or_else(|| Error::Wkt("Not a crs".into()))?;
+
+    let mut buf = String::new();
+    let proj_string = Formatter::new_string(&mut buf)
+        .format(&node)
+        .ok()
+        .map(|_| buf);
+
+    let datum = node.datum();
+    Ok(CrsInfo {
+        name: node.crs_name().unwrap_or_default().into(),
+        crs_type,
+        authority: node
+            .authority()
+            .map(|auth| (auth.name.into(), auth.code.into())),
+        datum_name: datum.map(|d| d.name.into()),
+        ellipsoid_name: datum.map(|d| d.ellipsoid.name.into()),
+        proj_string,
+    })
+}
+
+/// Return the EPSG code of a wkt crs
+///
+/// Return `None` if the crs is not identified by an EPSG code.
+pub fn wkt_epsg_code(i: &str) -> Result<Option<&str>> {
+    let node = Builder::new().parse(i)?;
+    Ok(node
+        .authority()
+        .filter(|auth| auth.name == "EPSG")
+        .map(|auth| auth.code))
+}
+
+/// Return true if the wkt string is a geographic crs
+///
+/// Only the first keyword is checked, the wkt is not parsed.
+pub fn wkt_is_geographic(i: &str) -> bool {
+    parser::first_keyword(i).map_or(false, |key| GEOGRAPHIC_KEYWORDS.contains(&key))
+}
+
+/// Return true if the wkt string is a projected crs
+///
+/// Only the first keyword is checked, the wkt is not parsed.
+pub fn wkt_is_projected(i: &str) -> bool {
+    parser::first_keyword(i).map_or(false, |key| PROJECTED_KEYWORDS.contains(&key))
+}
+
+const GEOGRAPHIC_KEYWORDS: [&str; 5] = [
+    "GEOGCS",
+    "GEOGCRS",
+    "GEOGRAPHICCRS",
+    "BASEGEOGCRS",
+    "BASEGEODCRS",
+];
+
+const PROJECTED_KEYWORDS: [&str; 3] = ["PROJCS", "PROJCRS", "PROJECTEDCRS"];
+
+/// Return the metric property preserved by the projection
+/// of a projected wkt crs
+pub fn wkt_projection_property(i: &str) -> Result<ProjectionProperty> {
+    let node = Builder::new().parse(i)?;
+    let projcs = projected_crs(&node)?;
+    methods::find_method_mapping(&projcs.projection.method)
+        .map(|(m, _)| m.projection_property())
+        .ok_or_else(|| {
+            Error::Wkt(
+                format!(
+                    "No projection mapping found for {:?}",
+                    projcs.projection.method
+                )
+                .into(),
+            )
+        })
+}
+
+/// Return the authority `(name, code)` of a projected wkt crs
+///
+/// Return `None` if the crs has no authority.
+pub fn wkt_extract_projcs_authority(i: &str) -> Result<Option<(&str, &str)>> {
+    let node = Builder::new().parse(i)?;
+    let projcs = projected_crs(&node)?;
+    Ok(projcs.authority.as_ref().map(|auth| (auth.name, auth.code)))
+}
+
+// Return the projected crs of a root node
+fn projected_crs<'a, 'b>(node: &'b Node<'a>) -> Result<&'b model::Projcs<'a>> {
+    use model::Horizontalcrs;
+
+    match node {
+        Node::PROJCRS(cs) => Ok(cs),
+        Node::COMPOUNDCRS(crs) => match &crs.h_crs {
+            Horizontalcrs::Projcs(cs) => Ok(cs),
+            _ => Err(Error::Wkt("Not a projected crs".into())),
+        },
+        _ => Err(Error::Wkt("Not a projected crs".into())),
+    }
+}
+
 #[cfg(target_arch = "wasm32")]
 #[global_allocator]
 static ALLOC: wee_alloc::WeeAlloc = wee_alloc::WeeAlloc::INIT;
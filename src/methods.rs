@@ -4,7 +4,10 @@
 use crate::consts::methods;
 use crate::model::Parameter;
 use crate::params::ParamMapping;
+use crate::report::MatchKind;
 
+/// Mapping between a WKT projection method and
+/// a proj projection
 pub struct MethodMapping {
     wkt2_name: &'static str,
     epsg_code: &'static str,
@@ -12,9 +15,32 @@ pub struct MethodMapping {
     proj_name: &'static str,
     proj_aux: &'static str,
     param_mapping: &'static [&'static ParamMapping],
+    property: ProjectionProperty,
+}
+
+/// Metric property preserved by a projection
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProjectionProperty {
+    Conformal,
+    EqualArea,
+    Equidistant,
+    Perspective,
+    Other,
 }
 
 impl MethodMapping {
+    pub fn wkt2_name(&self) -> &'static str {
+        self.wkt2_name
+    }
+
+    pub fn wkt1_name(&self) -> &'static str {
+        self.wkt1_name
+    }
+
+    pub fn epsg_code(&self) -> &'static str {
+        self.epsg_code
+    }
+
     pub fn proj_name(&self) -> &'static str {
         self.proj_name
     }
@@ -23,31 +49,66 @@ impl MethodMapping {
         self.proj_aux
     }
 
-    /// Look up for mapped proj parameter
-    pub fn find_proj_param(&self, p: &Parameter) -> Option<&ParamMapping> {
-        if p.name.is_empty() {
-            None
-        } else if let Some(auth) = &p.authority {
-            if auth.name == "EPSG" {
-                self.param_mapping
-                    .iter()
-                    .find(|pp| !pp.proj_name.is_empty() && pp.epsg_code == auth.code)
-            } else {
-                None
-            }
+    pub fn projection_property(&self) -> ProjectionProperty {
+        self.property
+    }
+
+    /// Return the parameter mappings for this method
+    pub fn parameters(&self) -> &'static [&'static ParamMapping] {
+        self.param_mapping
+    }
+
+    /// Return the number of parameters exported to proj
+    pub fn required_param_count(&self) -> usize {
+        self.required_params().count()
+    }
+
+    /// Iterate over the parameters exported to proj
+    pub fn required_params(&self) -> impl Iterator<Item = &'static ParamMapping> {
+        self.param_mapping
+            .iter()
+            .copied()
+            .filter(|pm| !pm.proj_name.is_empty())
+    }
+
+    /// Look up for mapped proj parameter and return
+    /// how the parameter was matched
+    pub fn find_proj_param(&self, p: &Parameter) -> Option<(&ParamMapping, MatchKind)> {
+        self.find_proj_params(p).next()
+    }
+
+    /// Look up for all proj parameters mapped from
+    /// the parameter `p`
+    ///
+    /// A WKT parameter may be emitted as several proj parameters
+    /// (i.e `+lat_0` and `+lat_1` for Lambert Conic Conformal 1SP).
+    pub fn find_proj_params<'a>(
+        &'a self,
+        p: &'a Parameter,
+    ) -> impl Iterator<Item = (&'static ParamMapping, MatchKind)> + 'a {
+        let kind = if p.authority.is_some() {
+            MatchKind::Epsg
         } else {
-            self.param_mapping.iter().find(|pp| {
-                pp.wkt2_name.eq_ignore_ascii_case(p.name)
-                    || pp.wkt1_name.eq_ignore_ascii_case(p.name)
+            MatchKind::Name
+        };
+        self.param_mapping
+            .iter()
+            .filter(move |pp| {
+                if p.name.is_empty() {
+                    false
+                } else if let Some(auth) = &p.authority {
+                    auth.name == "EPSG" && pp.epsg_code == auth.code
+                } else {
+                    pp.matches_name(p.name)
+                }
             })
-        }
-        .copied()
+            .map(move |pp| (*pp, kind))
     }
 }
 
 macro_rules! method {
     {$wkt2:ident, $wkt1_name:expr, $proj_name:expr, $proj_aux:expr,
-     $mapping:expr} => {
+     $mapping:expr, $property:ident} => {
         MethodMapping {
             wkt2_name: methods::$wkt2.name,
             epsg_code: methods::$wkt2.code,
@@ -55,6 +116,7 @@ macro_rules! method {
             proj_name: $proj_name,
             proj_aux: $proj_aux,
             param_mapping: $mapping,
+            property: ProjectionProperty::$property,
         }
     };
 }
@@ -83,8 +145,9 @@ mod parameters {
         &params::FALSE_NORTHING,
     ];
 
-    pub const LCC_1SP: [&ParamMapping; 5] = [
+    pub const LCC_1SP: [&ParamMapping; 6] = [
         &params::LAT_LCC_1SP,
+        &params::LAT_0_LCC_1SP,
         &params::LONGITUDE_NAT_ORIGIN,
         &params::SCALE_FACTOR,
         &params::FALSE_EASTING,
@@ -141,6 +204,13 @@ mod parameters {
         &params::FALSE_NORTHING,
     ];
 
+    pub const CEA: [&ParamMapping; 4] = [
+        &params::LAT_1ST_PARALLEL_LAT_TS,
+        &params::LONGITUDE_NAT_ORIGIN,
+        &params::FALSE_EASTING,
+        &params::FALSE_NORTHING,
+    ];
+
     pub const POLAR_STEREO: [&ParamMapping; 4] = [
         &params::LAT_STD_PARALLEL,
         &params::LONG_ORIGIN,
@@ -148,6 +218,13 @@ mod parameters {
         &params::FALSE_NORTHING,
     ];
 
+    pub const ESRI_POLAR_STEREO: [&ParamMapping; 4] = [
+        &params::LAT_1ST_PARALLEL_LAT_TS,
+        &params::LONG_ORIGIN,
+        &params::FALSE_EASTING,
+        &params::FALSE_NORTHING,
+    ];
+
     pub const OBLIQUE_STEREO: [&ParamMapping; 5] = [
         &params::LATITUDE_NAT_ORIGIN,
         &params::LONGITUDE_NAT_ORIGIN,
@@ -155,52 +232,361 @@ mod parameters {
         &params::FALSE_EASTING,
         &params::FALSE_NORTHING,
     ];
+
+    pub const KROVAK: [&ParamMapping; 7] = [
+        &params::LAT_CENTRE_LAT_CENTER,
+        &params::LONG_CENTRE_LONG_CENTER,
+        &params::COLATITUDE_CONE_AXIS,
+        &params::LATITUDE_PSEUDO_STD_PARALLEL,
+        &params::SCALE_FACTOR_PSEUDO_STD_PARALLEL,
+        &params::FALSE_EASTING,
+        &params::FALSE_NORTHING,
+    ];
+
+    // Variant A: false origin at the natural origin
+    pub const HOTINE_OBLIQUE_MERCATOR_VARIANT_A: [&ParamMapping; 7] = [
+        &params::LAT_CENTRE_LAT_CENTER,
+        &params::LON_CENTRE_LON_CENTER_LONC,
+        &params::AZIMUTH,
+        &params::ANGLE_TO_SKEW_GRID,
+        &params::SCALE_FACTOR_INITIALLINE,
+        &params::FALSE_EASTING,
+        &params::FALSE_NORTHING,
+    ];
+
+    // Variant B: false origin at the projection centre
+    pub const HOTINE_OBLIQUE_MERCATOR_VARIANT_B: [&ParamMapping; 7] = [
+        &params::LAT_CENTRE_LAT_CENTER,
+        &params::LON_CENTRE_LON_CENTER_LONC,
+        &params::AZIMUTH,
+        &params::ANGLE_TO_SKEW_GRID,
+        &params::SCALE_FACTOR_INITIALLINE,
+        &params::FALSE_EASTING_PROJECTION_CENTRE,
+        &params::FALSE_NORTHING_PROJECTION_CENTRE,
+    ];
 }
 
-pub const METHOD_MAPPINGS: [MethodMapping; 19] = [
-    method! {TRANSVERSE_MERCATOR, "Transverse_Mercator", "tmerc", "", &parameters::NAT_ORIGIN_SCALE_K},
+pub const METHOD_MAPPINGS: [MethodMapping; 35] = [
+    method! {TRANSVERSE_MERCATOR, "Transverse_Mercator", "tmerc", "", &parameters::NAT_ORIGIN_SCALE_K, Conformal},
     method! {TRANSVERSE_MERCATOR_SOUTH_ORIENTATED, "Transverse_Mercator_South_Orientated", "tmerc", "+axis=wsu",
-    &parameters::NAT_ORIGIN_SCALE_K},
-    method! {ALBERS_EQUAL_AREA, "Albers_Conic_Equal_Area", "aea", "", &parameters::AEA},
-    method! {LAMBERT_CONIC_CONFORMAL_1SP, "Lambert_Conformal_Conic_1SP", "lcc", "", &parameters::LCC_1SP},
+    &parameters::NAT_ORIGIN_SCALE_K, Conformal},
+    method! {ALBERS_EQUAL_AREA, "Albers_Conic_Equal_Area", "aea", "", &parameters::AEA, EqualArea},
+    method! {LAMBERT_CONIC_CONFORMAL_1SP, "Lambert_Conformal_Conic_1SP", "lcc", "", &parameters::LCC_1SP, Conformal},
     method! {LAMBERT_CONIC_CONFORMAL_2SP, "Lambert_Conformal_Conic_2SP", "lcc", "",
-    &parameters::LCC_2SP},
+    &parameters::LCC_2SP, Conformal},
     // no mapping to WKT1
-    method! {LAMBERT_CONIC_CONFORMAL_2SP_MICHIGAN, "", "lcc", "", &parameters::LCC_2SP_MICHIGAN},
+    method! {LAMBERT_CONIC_CONFORMAL_2SP_MICHIGAN, "", "lcc", "", &parameters::LCC_2SP_MICHIGAN, Conformal},
     method! {LAMBERT_CONIC_CONFORMAL_2SP_BELGIUM, "Lambert_Conformal_Conic_2SP_Belgium", "lcc", "",
-    &parameters::LCC_2SP},
-    method! {LAMBERT_AZIMUTHAL_EQUAL_AREA, "Lambert_Azimuthal_Equal_Area", "laea", "", &parameters::LAEA},
+    &parameters::LCC_2SP, Conformal},
+    method! {LAMBERT_AZIMUTHAL_EQUAL_AREA, "Lambert_Azimuthal_Equal_Area", "laea", "", &parameters::LAEA, EqualArea},
     method! {LAMBERT_AZIMUTHAL_EQUAL_AREA_SPHERICAL, "Lambert_Azimuthal_Equal_Area", "laea", "+R_A",
-    &parameters::LAEA},
-    method! {MERCATOR_VARIANT_A, "Mercator_1SP", "merc", "", &parameters::MERC_1SP},
-    method! {MERCATOR_VARIANT_B, "Mercator_2SP", "merc", "", &parameters::MERC_2SP},
+    &parameters::LAEA, EqualArea},
+    method! {MERCATOR_VARIANT_A, "Mercator_1SP", "merc", "", &parameters::MERC_1SP, Conformal},
+    method! {MERCATOR_VARIANT_B, "Mercator_2SP", "merc", "", &parameters::MERC_2SP, Conformal},
+    method! {CASSINI_SOLDNER, "Cassini_Soldner", "cass", "", &parameters::NAT_ORIGIN, Equidistant},
     method! {POPULAR_VISUALISATION_PSEUDO_MERCATOR, "Popular_Visualisation_Pseudo_Mercator", "webmerc", "",
-    &parameters::NAT_ORIGIN},
-    method! {PROJ_WKT2_NAME_MOLLWEIDE, "Mollweide", "moll", "", &parameters::LONG_NAT_ORIGIN},
-    method! {PROJ_WKT2_NAME_WAGNER_IV, "Wagner_IV", "wag4", "", &parameters::LONG_NAT_ORIGIN},
-    method! {PROJ_WKT2_NAME_WAGNER_V, "Wagner_V", "wag5", "", &parameters::LONG_NAT_ORIGIN},
+    &parameters::NAT_ORIGIN, Other},
+    method! {PROJ_WKT2_NAME_MOLLWEIDE, "Mollweide", "moll", "", &parameters::LONG_NAT_ORIGIN, EqualArea},
+    method! {PROJ_WKT2_NAME_WAGNER_IV, "Wagner_IV", "wag4", "", &parameters::LONG_NAT_ORIGIN, EqualArea},
+    method! {PROJ_WKT2_NAME_WAGNER_V, "Wagner_V", "wag5", "", &parameters::LONG_NAT_ORIGIN, Other},
+    method! {PROJ_WKT2_NAME_METHOD_ROBINSON, "Robinson", "robin", "", &parameters::LONG_NAT_ORIGIN, Other},
+    method! {PROJ_WKT2_NAME_METHOD_NATURAL_EARTH, "Natural_Earth", "natearth", "",
+    &parameters::LONG_NAT_ORIGIN, Other},
+    method! {PROJ_WKT2_NAME_METHOD_NATURAL_EARTH_II, "Natural_Earth_II", "natearth2", "",
+    &parameters::LONG_NAT_ORIGIN, Other},
     method! {OBLIQUE_STEREOGRAPHIC, "Oblique_Stereographic", "sterea", "",
-    &parameters::OBLIQUE_STEREO},
-    method! {POLAR_STEREOGRAPHIC_VARIANT_A, "Polar_Stereographic", "stere", "", &parameters::OBLIQUE_STEREO},
-    method! {POLAR_STEREOGRAPHIC_VARIANT_B, "Polar_Stereographic", "stere", "", &parameters::POLAR_STEREO},
-    method! {PROJ_WKT2_NAME_METHOD_STEREOGRAPHIC, "Stereographic", "stere", "", &parameters::OBLIQUE_STEREO},
+    &parameters::OBLIQUE_STEREO, Conformal},
+    method! {POLAR_STEREOGRAPHIC_VARIANT_A, "Polar_Stereographic", "stere", "", &parameters::OBLIQUE_STEREO, Conformal},
+    method! {POLAR_STEREOGRAPHIC_VARIANT_B, "Polar_Stereographic", "stere", "", &parameters::POLAR_STEREO, Conformal},
+    method! {PROJ_WKT2_NAME_METHOD_STEREOGRAPHIC, "Stereographic", "stere", "", &parameters::OBLIQUE_STEREO, Conformal},
+    method! {HOTINE_OBLIQUE_MERCATOR_VARIANT_A, "Hotine_Oblique_Mercator", "omerc", "+no_uoff",
+    &parameters::HOTINE_OBLIQUE_MERCATOR_VARIANT_A, Conformal},
+    method! {HOTINE_OBLIQUE_MERCATOR_VARIANT_B, "Hotine_Oblique_Mercator_Azimuth_Center", "omerc", "",
+    &parameters::HOTINE_OBLIQUE_MERCATOR_VARIANT_B, Conformal},
+    // Southing and westing
+    method! {KROVAK, "Krovak", "krovak", "+axis=swu", &parameters::KROVAK, Conformal},
+    // Easting and northing, negative values in the area of use.
+    // No distinct WKT1 name, see `oriented_method_mapping`
+    method! {KROVAK_NORTH_ORIENTED, "", "krovak", "", &parameters::KROVAK, Conformal},
+    method! {LAMBERT_CYLINDRICAL_EQUAL_AREA, "Cylindrical_Equal_Area", "cea", "", &parameters::CEA, EqualArea},
+    method! {LAMBERT_CYLINDRICAL_EQUAL_AREA_SPHERICAL, "Cylindrical_Equal_Area", "cea", "+R_A",
+    &parameters::CEA, EqualArea},
+    // Cylindrical equal area with lat_ts=30
+    method! {PROJ_WKT2_NAME_METHOD_BEHRMANN, "Behrmann", "cea", "+lat_ts=30",
+    &parameters::LONG_NAT_ORIGIN, EqualArea},
+    method! {PROJ_WKT2_NAME_METHOD_GALL_STEREOGRAPHIC, "Gall_Stereographic", "gall", "",
+    &parameters::LONG_NAT_ORIGIN, Other},
+    // no mapping to WKT1
+    method! {GUAM_PROJECTION, "", "aeqd", "+guam", &parameters::NAT_ORIGIN, Equidistant},
+    method! {MODIFIED_AZIMUTHAL_EQUIDISTANT, "", "aeqd", "", &parameters::NAT_ORIGIN, Equidistant},
+    // ESRI
+    method! {ESRI_STEREOGRAPHIC_NORTH_POLE, "Stereographic_North_Pole", "stere", "+lat_0=90",
+    &parameters::ESRI_POLAR_STEREO, Conformal},
+    method! {ESRI_STEREOGRAPHIC_SOUTH_POLE, "Stereographic_South_Pole", "stere", "+lat_0=-90",
+    &parameters::ESRI_POLAR_STEREO, Conformal},
 ];
 
-use crate::model::Method;
+// Alternative WKT1 names as `(alias, WKT1 name)`
+const WKT1_ALIASES: [(&str, &str); 1] = [("Gall", "Gall_Stereographic")];
+
+use crate::model::{Axis, AxisDirection, Method};
+
+/// Return the method mapping table
+pub fn method_mappings() -> &'static [MethodMapping] {
+    &METHOD_MAPPINGS
+}
+
+/// Iterate over supported methods as
+/// `(wkt2_name, wkt1_name, epsg_code, proj_name)` tuples
+pub fn supported_methods(
+) -> impl Iterator<Item = (&'static str, &'static str, &'static str, &'static str)> {
+    METHOD_MAPPINGS
+        .iter()
+        .map(|m| (m.wkt2_name, m.wkt1_name, m.epsg_code, m.proj_name))
+}
 
 /// Retrieve method mappinf from model
 ///
-/// Trust EPSG code first if available, otherwise check name
-pub fn find_method_mapping(me: &Method) -> Option<&'static MethodMapping> {
+/// Trust EPSG code first if available, otherwise check name.
+/// Return the mapping and how the method was matched.
+pub fn find_method_mapping(me: &Method) -> Option<(&'static MethodMapping, MatchKind)> {
     if me.name.is_empty() {
+        return None;
+    }
+    // Fall back to the name for non EPSG or unknown authorities
+    me.authority
+        .as_ref()
+        .filter(|auth| auth.name == "EPSG")
+        .and_then(|auth| METHOD_MAPPINGS.iter().find(|m| m.epsg_code == auth.code))
+        .map(|m| (m, MatchKind::Epsg))
+        .or_else(|| {
+            METHOD_MAPPINGS
+                .iter()
+                .find(|m| m.wkt2_name.eq_ignore_ascii_case(me.name) || has_wkt1_name(m, me.name))
+                .map(|m| (m, MatchKind::Name))
+        })
+}
+
+/// Return the method variant implied by the crs axes
+///
+/// WKT1 has a single Krovak method: east/north axes
+/// select Krovak (North Orientated). Missing axes default
+/// to east/north in WKT1 and are north orientated too.
+pub(crate) fn oriented_method_mapping(
+    m: &'static MethodMapping,
+    axes: &[Axis],
+) -> &'static MethodMapping {
+    let north_orientated = match axes {
+        [] => true,
+        [a, b, ..] => a.direction == AxisDirection::East && b.direction == AxisDirection::North,
+        _ => false,
+    };
+    if m.epsg_code == methods::KROVAK.code && north_orientated {
+        METHOD_MAPPINGS
+            .iter()
+            .find(|m| m.epsg_code == methods::KROVAK_NORTH_ORIENTED.code)
+            .unwrap_or(m)
+    } else {
+        m
+    }
+}
+
+/// Retrieve method mapping by its WKT2 name only (case insensitive)
+pub fn find_method_by_wkt2_name(name: &str) -> Option<&'static MethodMapping> {
+    if name.is_empty() {
         None
-    } else if let Some(auth) = &me.authority {
+    } else {
         METHOD_MAPPINGS
             .iter()
-            .find(|m| auth.name == "EPSG" && m.epsg_code == auth.code)
+            .find(|m| m.wkt2_name.eq_ignore_ascii_case(name))
+    }
+}
+
+/// Retrieve method mapping by its WKT1 name only (case insensitive)
+pub fn find_method_by_wkt1_name(name: &str) -> Option<&'static MethodMapping> {
+    if name.is_empty() {
+        None
     } else {
-        METHOD_MAPPINGS.iter().find(|m| {
-            m.wkt2_name.eq_ignore_ascii_case(me.name) || m.wkt1_name.eq_ignore_ascii_case(me.name)
-        })
+        METHOD_MAPPINGS.iter().find(|m| has_wkt1_name(m, name))
+    }
+}
+
+// Compare the WKT1 name or its aliases (case insensitive)
+fn has_wkt1_name(m: &MethodMapping, name: &str) -> bool {
+    !m.wkt1_name.is_empty()
+        && (m.wkt1_name.eq_ignore_ascii_case(name)
+            || WKT1_ALIASES.iter().any(|(alias, wkt1_name)| {
+                *wkt1_name == m.wkt1_name && alias.eq_ignore_ascii_case(name)
+            }))
+}
+
+// ==============================
+//  Tests
+// ==============================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::{fixtures, setup};
+    use crate::wkt_projection_property;
+
+    #[test]
+    fn method_projection_property() {
+        use ProjectionProperty::*;
+
+        let property = |proj_name| {
+            METHOD_MAPPINGS
+                .iter()
+                .filter(|m| m.proj_name() == proj_name)
+                .map(|m| m.projection_property())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(property("tmerc"), [Conformal; 2]);
+        assert_eq!(property("lcc"), [Conformal; 4]);
+        assert_eq!(property("merc"), [Conformal; 2]);
+        assert_eq!(property("sterea"), [Conformal]);
+        assert_eq!(property("stere"), [Conformal; 5]);
+        assert_eq!(property("aea"), [EqualArea]);
+        assert_eq!(property("laea"), [EqualArea; 2]);
+        assert_eq!(property("moll"), [EqualArea]);
+        assert_eq!(property("wag4"), [EqualArea]);
+        assert_eq!(property("wag5"), [Other]);
+        assert_eq!(property("webmerc"), [Other]);
+        assert_eq!(property("aeqd"), [Equidistant; 2]);
+        assert_eq!(property("cea"), [EqualArea; 3]);
+        assert_eq!(property("gall"), [Other]);
+    }
+
+    #[test]
+    fn find_method_by_wkt1_alias() {
+        let gall = find_method_by_wkt1_name("Gall_Stereographic").unwrap();
+        assert!(std::ptr::eq(
+            find_method_by_wkt1_name("GALL").unwrap(),
+            gall
+        ));
+        assert!(std::ptr::eq(
+            find_method_by_wkt2_name(gall.wkt2_name()).unwrap(),
+            gall
+        ));
+        // Aliases are not mapping entries
+        assert_eq!(
+            METHOD_MAPPINGS
+                .iter()
+                .filter(|m| m.wkt2_name == gall.wkt2_name)
+                .count(),
+            1
+        );
+    }
+
+    #[test]
+    fn inspect_method_mappings() {
+        assert_eq!(method_mappings().len(), supported_methods().count());
+        assert!(supported_methods().any(|m| m
+            == (
+                "Transverse Mercator",
+                "Transverse_Mercator",
+                "9807",
+                "tmerc"
+            )));
+
+        let tmerc = method_mappings()
+            .iter()
+            .find(|m| m.epsg_code() == "9807")
+            .unwrap();
+        assert_eq!(
+            tmerc
+                .parameters()
+                .iter()
+                .map(|p| p.proj_name)
+                .collect::<Vec<_>>(),
+            ["lat_0", "lon_0", "k", "x_0", "y_0"]
+        );
+    }
+
+    #[test]
+    fn wkt_to_projection_property() {
+        setup();
+        assert_eq!(
+            wkt_projection_property(fixtures::WKT_PROJCS_NAD83).unwrap(),
+            ProjectionProperty::Conformal
+        );
+        assert_eq!(
+            wkt_projection_property(fixtures::WKT_PROJCRS_GUAM).unwrap(),
+            ProjectionProperty::Equidistant
+        );
+        assert!(wkt_projection_property(fixtures::WKT_GEOGCS_WGS84).is_err());
+    }
+
+    #[test]
+    fn esri_parameter_aliases() {
+        let laea = method_mappings()
+            .iter()
+            .find(|m| m.epsg_code() == "9820")
+            .unwrap();
+
+        let param = |name| Parameter {
+            name,
+            value: "0",
+            unit: None,
+            authority: None,
+        };
+
+        let proj_name = |name| {
+            laea.find_proj_param(&param(name))
+                .map(|(pp, _)| pp.proj_name)
+        };
+
+        assert_eq!(proj_name("Latitude_Of_Center"), Some("lat_0"));
+        assert_eq!(proj_name("Latitude_Of_Origin"), Some("lat_0"));
+        assert_eq!(proj_name("Longitude_Of_Center"), Some("lon_0"));
+        assert_eq!(proj_name("Central_Meridian"), Some("lon_0"));
+        assert_eq!(proj_name("Standard_Parallel_1"), None);
+    }
+
+    #[test]
+    fn polar_stereo_longitude_aliases() {
+        let param = |name| Parameter {
+            name,
+            value: "0",
+            unit: None,
+            authority: None,
+        };
+
+        let polar_stereo = method_mappings()
+            .iter()
+            .filter(|m| m.proj_name() == "stere" && m.epsg_code() != "9810")
+            .filter(|m| m.parameters().iter().any(|p| p.proj_name == "lat_ts"))
+            .collect::<Vec<_>>();
+        assert_eq!(polar_stereo.len(), 3);
+
+        for m in polar_stereo {
+            for name in [
+                "central_meridian",
+                "Longitude_Of_Origin",
+                "Straight_Vertical_Longitude_From_Pole",
+            ] {
+                assert_eq!(
+                    m.find_proj_param(&param(name)).map(|(pp, _)| pp.proj_name),
+                    Some("lon_0"),
+                    "{} for {}",
+                    name,
+                    m.wkt1_name()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn method_lookup_by_name() {
+        let m = find_method_by_wkt2_name("transverse mercator").unwrap();
+        assert_eq!(m.epsg_code(), "9807");
+        assert!(find_method_by_wkt1_name("Transverse Mercator").is_none());
+
+        let m = find_method_by_wkt1_name("transverse_mercator").unwrap();
+        assert_eq!(m.epsg_code(), "9807");
+        assert!(find_method_by_wkt2_name("Transverse_Mercator").is_none());
+
+        assert!(find_method_by_wkt1_name("").is_none());
+        assert!(find_method_by_wkt2_name("").is_none());
     }
 }
@@ -1,18 +1,171 @@
 //!
 //! Projection representation model
 //!
+//! Quoted strings are borrowed from the WKT with their double
+//! quotes escaped, see [`unescape`](crate::parser::unescape).
+//!
+use crate::builder::{parse_number, Node};
+
 #[derive(Debug, PartialEq)]
 pub struct Geogcs<'a> {
     pub name: &'a str,
     pub datum: Datum<'a>,
     pub unit: Option<Unit<'a>>,
+    pub prime_meridian: Option<PrimeMeridian<'a>>,
+    pub cs: Option<CoordinateSystem>,
+    pub usage: Option<UsageInfo<'a>>,
+    pub authority: Option<Authority<'a>>,
+    pub remark: Option<&'a str>,
+}
+
+impl Geogcs<'_> {
+    /// Return the bounding box of the area of use
+    /// as (south, west, north, east)
+    pub fn bounding_box(&self) -> Option<[f64; 4]> {
+        self.usage.as_ref().and_then(|u| u.bounding_box)
+    }
+
+    /// Return true if the coordinate system is 3D
+    pub fn is_3d(&self) -> bool {
+        self.cs.as_ref().map_or(false, |cs| cs.is_3d())
+    }
+
+    /// Return the prime meridian longitude in degrees
+    ///
+    /// Return 0.0 (Greenwich) if no prime meridian is defined.
+    pub fn prime_meridian_longitude(&self) -> f64 {
+        self.prime_meridian
+            .as_ref()
+            .map(|pm| pm.longitude_degrees())
+            .unwrap_or(0.0)
+    }
+
+    /// Return the EPSG code of the crs
+    pub fn epsg_code(&self) -> Option<&str> {
+        self.authority
+            .as_ref()
+            .filter(|auth| auth.name == "EPSG")
+            .map(|auth| auth.code)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct PrimeMeridian<'a> {
+    pub name: &'a str,
+    pub longitude: f64,
+    pub unit: Option<Unit<'a>>,
+}
+
+impl PrimeMeridian<'_> {
+    /// Return the longitude in degrees
+    ///
+    /// The longitude is converted using the prime meridian
+    /// angle unit if defined (WKT2), otherwise it is
+    /// assumed to be in degrees.
+    pub fn longitude_degrees(&self) -> f64 {
+        match &self.unit {
+            Some(unit) if !unit.is_degree() => (self.longitude * unit.factor).to_degrees(),
+            _ => self.longitude,
+        }
+    }
 }
 
 #[derive(Debug, PartialEq)]
 pub struct Datum<'a> {
     pub name: &'a str,
     pub ellipsoid: Ellipsoid<'a>,
-    pub to_wgs84: Vec<&'a str>,
+    pub to_wgs84: ToWgs84<'a>,
+    /// True if a `TOWGS84` node is present
+    pub has_explicit_towgs84: bool,
+    pub nadgrids: Option<&'a str>,
+    pub authority: Option<Authority<'a>>,
+}
+
+impl Datum<'_> {
+    /// Return true if the datum name identifies WGS84
+    pub fn is_wgs84(&self) -> bool {
+        [
+            "WGS_1984",
+            "WGS 1984",
+            "WGS84",
+            "WGS 84",
+            "World Geodetic System 1984",
+        ]
+        .iter()
+        .any(|n| n.eq_ignore_ascii_case(self.name))
+    }
+
+    /// Return true if the datum is unambiguously WGS84
+    ///
+    /// The datum must be identified either by its EPSG code (6326),
+    /// its ellipsoid EPSG code (7030) or its name, must use the WGS84
+    /// ellipsoid parameters and must not define a non-null shift.
+    pub fn is_wgs84_fingerprint(&self) -> bool {
+        let is_epsg = |auth: &Option<Authority>, code| matches!(auth, Some(auth) if auth.name == "EPSG" && auth.code == code);
+        let ellps = &self.ellipsoid;
+        (is_epsg(&self.authority, "6326") || is_epsg(&ellps.authority, "7030") || self.is_wgs84())
+            && ellps.unit.as_ref().map_or(true, |u| u.factor == 1.0)
+            && parse_number(ellps.a).map_or(false, |a| a == 6378137.0)
+            && parse_number(ellps.rf).map_or(false, |rf| rf == 298.257223563)
+            && self.nadgrids.is_none()
+            && self
+                .to_wgs84
+                .iter()
+                .all(|s| parse_number(s).map_or(false, |v| v == 0.0))
+    }
+
+    /// Return the TOWGS84 parameters as a 7 parameters
+    /// Helmert transformation.
+    ///
+    /// 3 parameters shifts are padded with zeros.
+    /// Return `None` if no shift is defined and the datum is
+    /// not identified as WGS84.
+    pub fn wgs84_shift_params(&self) -> Option<[f64; 7]> {
+        if self.to_wgs84.is_empty() && !self.is_wgs84() {
+            return None;
+        }
+        let mut params = [0.0; 7];
+        for (p, s) in params.iter_mut().zip(&self.to_wgs84) {
+            *p = parse_number(s).ok()?;
+        }
+        Some(params)
+    }
+}
+
+/// TOWGS84 shift parameters
+#[derive(Debug, Default, PartialEq)]
+pub struct ToWgs84<'a>(Vec<&'a str>);
+
+impl<'a> ToWgs84<'a> {
+    /// Return the number of parameters
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Return true if no shift is defined
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Return an iterator over the parameters
+    pub fn iter(&self) -> std::iter::Copied<std::slice::Iter<'_, &'a str>> {
+        self.0.iter().copied()
+    }
+}
+
+impl<'a> From<Vec<&'a str>> for ToWgs84<'a> {
+    fn from(params: Vec<&'a str>) -> Self {
+        Self(params)
+    }
+}
+
+impl<'a, 'b> IntoIterator for &'b ToWgs84<'a> {
+    type Item = &'a str;
+    type IntoIter = std::iter::Copied<std::slice::Iter<'b, &'a str>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
 }
 
 #[derive(Debug, PartialEq)]
@@ -21,6 +174,7 @@ pub struct Ellipsoid<'a> {
     pub a: &'a str,
     pub rf: &'a str,
     pub unit: Option<Unit<'a>>,
+    pub authority: Option<Authority<'a>>,
 }
 
 #[derive(Debug, PartialEq)]
@@ -29,6 +183,41 @@ pub struct Projcs<'a> {
     pub geogcs: Geogcs<'a>,
     pub projection: Projection<'a>,
     pub unit: Option<Unit<'a>>,
+    pub cs: Option<CoordinateSystem>,
+    pub usage: Option<UsageInfo<'a>>,
+    pub axes: Vec<Axis<'a>>,
+    pub authority: Option<Authority<'a>>,
+    pub remark: Option<&'a str>,
+}
+
+impl Projcs<'_> {
+    /// Return the bounding box of the area of use
+    /// as (south, west, north, east)
+    pub fn bounding_box(&self) -> Option<[f64; 4]> {
+        self.usage.as_ref().and_then(|u| u.bounding_box)
+    }
+
+    /// Return true if the coordinate system is 3D
+    pub fn is_3d(&self) -> bool {
+        self.cs.as_ref().map_or(false, |cs| cs.is_3d())
+    }
+
+    /// Return the linear unit of the projected crs
+    ///
+    /// In WKT2 the unit may be defined in the `AXIS` nodes.
+    pub fn linear_unit(&self) -> Option<&Unit<'_>> {
+        self.unit
+            .as_ref()
+            .or_else(|| self.axes.iter().find_map(|a| a.unit.as_ref()))
+    }
+
+    /// Return the EPSG code of the crs
+    pub fn epsg_code(&self) -> Option<&str> {
+        self.authority
+            .as_ref()
+            .filter(|auth| auth.name == "EPSG")
+            .map(|auth| auth.code)
+    }
 }
 
 #[derive(Debug, PartialEq)]
@@ -39,6 +228,41 @@ pub struct Projection<'a> {
     pub authority: Option<Authority<'a>>,
 }
 
+impl<'a> Projection<'a> {
+    /// Return the number of parameters
+    pub fn len(&self) -> usize {
+        self.parameters.len()
+    }
+
+    /// Return true if the projection has no parameters
+    pub fn is_empty(&self) -> bool {
+        self.parameters.is_empty()
+    }
+
+    /// Find a parameter by name (case insensitive)
+    pub fn find_parameter(&self, wkt1_name: &str) -> Option<&Parameter<'a>> {
+        self.parameters
+            .iter()
+            .find(|p| p.name.eq_ignore_ascii_case(wkt1_name))
+    }
+
+    /// Find a parameter by its EPSG code
+    pub fn find_parameter_by_epsg(&self, code: &str) -> Option<&Parameter<'a>> {
+        self.parameters.iter().find(
+            |p| matches!(&p.authority, Some(auth) if auth.name == "EPSG" && auth.code == code),
+        )
+    }
+}
+
+impl<'a, 'b> IntoIterator for &'b Projection<'a> {
+    type Item = &'b Parameter<'a>;
+    type IntoIter = std::slice::Iter<'b, Parameter<'a>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.parameters.iter()
+    }
+}
+
 #[derive(Debug, PartialEq)]
 pub struct Parameter<'a> {
     pub name: &'a str,
@@ -75,20 +299,127 @@ pub struct Unit<'a> {
     pub name: &'a str,
     pub factor: f64,
     pub unit_type: UnitType,
+    pub authority: Option<Authority<'a>>,
+}
+
+impl Unit<'_> {
+    /// Return true if the unit is a degree
+    ///
+    /// Check the EPSG code if defined (9102 or 9122), otherwise
+    /// the name or, as names are not normalized, the conversion
+    /// factor to radians.
+    pub fn is_degree(&self) -> bool {
+        match &self.authority {
+            Some(auth) if auth.name == "EPSG" => matches!(auth.code, "9102" | "9122"),
+            _ => {
+                self.name.eq_ignore_ascii_case("degree")
+                    || ((self.factor - 1f64.to_radians()) / self.factor).abs() < 1.0e-12
+            }
+        }
+    }
+
+    /// Return true if the unit is the unity scale
+    ///
+    /// Check the EPSG code if defined (9201), otherwise
+    /// the conversion factor.
+    pub fn is_unity(&self) -> bool {
+        match &self.authority {
+            Some(auth) if auth.name == "EPSG" => auth.code == "9201",
+            _ => self.factor == 1.0,
+        }
+    }
+}
+
+// WKT2 usage
+// see https://docs.ogc.org/is/18-010r7/18-010r7.html#38
+#[derive(Debug, PartialEq)]
+pub struct UsageInfo<'a> {
+    pub scope: Option<&'a str>,
+    pub area: Option<&'a str>,
+    /// Bounding box as (south, west, north, east)
+    pub bounding_box: Option<[f64; 4]>,
+}
+
+impl UsageInfo<'_> {
+    /// Return true if the bounding box spans the antimeridian
+    pub fn crosses_antimeridian(&self) -> bool {
+        self.bounding_box
+            .map(|[_, west, _, east]| west > east)
+            .unwrap_or(false)
+    }
+}
+
+// see https://docs.ogc.org/is/18-010r7/18-010r7.html#39
+#[derive(Debug, PartialEq)]
+pub enum AxisDirection<'a> {
+    North,
+    South,
+    East,
+    West,
+    Up,
+    Down,
+    /// Direction defined by a meridian angle in degrees
+    Meridian(f64),
+    Other(&'a str),
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Axis<'a> {
+    pub name: &'a str,
+    pub direction: AxisDirection<'a>,
+    pub unit: Option<Unit<'a>>,
+    pub order: Option<u8>,
+}
+
+// see https://docs.ogc.org/is/18-010r7/18-010r7.html#33
+#[derive(Debug, PartialEq)]
+pub enum CsType {
+    Cartesian,
+    Ellipsoidal,
+    Vertical,
+    Temporal,
+    Parametric,
+    Ordinal,
+    Unknown,
+}
+
+// WKT2 coordinate system
+#[derive(Debug, PartialEq)]
+pub struct CoordinateSystem {
+    pub cs_type: CsType,
+    pub dimension: u8,
+}
+
+impl CoordinateSystem {
+    /// Return true for a 3D coordinate system
+    pub fn is_3d(&self) -> bool {
+        self.dimension == 3
+    }
 }
 
 // see https://docs.ogc.org/is/18-010r7/18-010r7.html#125
 #[derive(Debug, PartialEq)]
 #[non_exhaustive]
+#[allow(clippy::large_enum_variant)]
 pub enum Horizontalcrs<'a> {
     Projcs(Projcs<'a>),
     Geogcs(Geogcs<'a>),
 }
 
-// TODO
 #[derive(Debug, PartialEq)]
 pub struct Verticalcrs<'a> {
     pub name: &'a str,
+    pub geoid_model: Option<GeoidModel<'a>>,
+    pub unit: Option<Unit<'a>>,
+    pub authority: Option<Authority<'a>>,
+    pub remark: Option<&'a str>,
+}
+
+// WKT 2019
+#[derive(Debug, PartialEq)]
+pub struct GeoidModel<'a> {
+    pub name: &'a str,
+    pub authority: Option<Authority<'a>>,
 }
 
 #[derive(Debug, PartialEq)]
@@ -96,4 +427,49 @@ pub struct Compoundcrs<'a> {
     pub name: &'a str,
     pub h_crs: Horizontalcrs<'a>,
     pub v_crs: Verticalcrs<'a>,
+    pub usage: Option<UsageInfo<'a>>,
+    pub authority: Option<Authority<'a>>,
+    pub remark: Option<&'a str>,
+}
+
+impl Compoundcrs<'_> {
+    /// Return the bounding box of the area of use
+    /// as (south, west, north, east)
+    ///
+    /// Fall back to the bounding box of the horizontal crs.
+    pub fn bounding_box(&self) -> Option<[f64; 4]> {
+        self.usage
+            .as_ref()
+            .and_then(|u| u.bounding_box)
+            .or_else(|| match &self.h_crs {
+                Horizontalcrs::Projcs(cs) => cs.bounding_box(),
+                Horizontalcrs::Geogcs(cs) => cs.bounding_box(),
+            })
+    }
+}
+
+/// Kind of a top-level CRS
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CrsKind {
+    Geographic,
+    Projected,
+    Compound,
+    Geocentric,
+    Vertical,
+}
+
+/// A CRS derived from a projected CRS
+///
+/// The deriving conversion is not supported.
+#[derive(Debug, PartialEq)]
+pub struct DerivedProjcrs<'a> {
+    pub name: &'a str,
+    pub base: Box<Node<'a>>,
+}
+
+/// A CRS with an optional coordinate epoch
+#[derive(Debug, PartialEq)]
+pub struct CoordinateMetadata<'a> {
+    pub crs: Box<Node<'a>>,
+    pub epoch: Option<f64>,
 }
@@ -19,13 +19,19 @@ const WKT1_LATITUDE_OF_CENTER: &str = "latitude_of_center";
 const WKT1_LONGITUDE_OF_CENTER: &str = "longitude_of_center";
 const WKT1_AZIMUTH: &str = "azimuth";
 const WKT1_RECTIFIED_GRID_ANGLE: &str = "rectified_grid_angle";
+const WKT1_LONGITUDE_OF_ORIGIN: &str = "longitude_of_origin";
+const WKT1_STRAIGHT_VERTICAL_LONGITUDE_FROM_POLE: &str = "straight_vertical_longitude_from_pole";
 
+/// Mapping between a WKT projection parameter and
+/// a proj parameter
 #[derive(Debug)]
 pub struct ParamMapping {
     pub proj_name: &'static str,
     pub wkt2_name: &'static str,
     pub epsg_code: &'static str,
     pub wkt1_name: &'static str,
+    /// Alternate WKT1 spellings (i.e ESRI)
+    pub aliases: &'static [&'static str],
     pub unit_type: UnitType,
 }
 
@@ -46,15 +52,27 @@ impl ParamMapping {
     const Y_0: &str = "y_0";
     const H: &str = "h";
     const NULL: &str = "";
+
+    /// Check if `name` matches the WKT2 name, the WKT1 name
+    /// or one of the aliases (case insensitive)
+    pub fn matches_name(&self, name: &str) -> bool {
+        self.wkt2_name.eq_ignore_ascii_case(name)
+            || self.wkt1_name.eq_ignore_ascii_case(name)
+            || self.aliases.iter().any(|a| a.eq_ignore_ascii_case(name))
+    }
 }
 
 macro_rules! map {
     ($name:ident, $wkt2:ident, $wkt1_name:expr, $unit_type:ident, $proj_name:ident) => {
+        map!($name, $wkt2, $wkt1_name, [], $unit_type, $proj_name);
+    };
+    ($name:ident, $wkt2:ident, $wkt1_name:expr, [$($alias:expr),*], $unit_type:ident, $proj_name:ident) => {
         pub(crate) const $name: ParamMapping = ParamMapping {
             proj_name: ParamMapping::$proj_name,
             wkt2_name: parameters::$wkt2.name,
             epsg_code: parameters::$wkt2.code,
             wkt1_name: $wkt1_name,
+            aliases: &[$($alias),*],
             unit_type: UnitType::$unit_type,
         };
     };
@@ -160,6 +178,7 @@ map!(
     LAT_FALSE_ORIGIN_LAT_OF_CENTER,
     LATITUDE_FALSE_ORIGIN,
     WKT1_LATITUDE_OF_CENTER,
+    [WKT1_LATITUDE_OF_ORIGIN],
     Angular,
     LAT_0
 );
@@ -168,6 +187,7 @@ map!(
     LONG_FALSE_ORIGIN_LONG_OF_CENTER,
     LONGITUDE_FALSE_ORIGIN,
     WKT1_LONGITUDE_OF_CENTER,
+    [WKT1_CENTRAL_MERIDIAN],
     Angular,
     LON_0
 );
@@ -213,6 +233,7 @@ map!(
     LAT_NAT_LAT_CENTER,
     LATITUDE_OF_NATURAL_ORIGIN,
     WKT1_LATITUDE_OF_CENTER,
+    [WKT1_LATITUDE_OF_ORIGIN],
     Angular,
     LAT_0
 );
@@ -221,6 +242,7 @@ map!(
     LONG_NAT_LONG_CENTER,
     LONGITUDE_OF_NATURAL_ORIGIN,
     WKT1_LONGITUDE_OF_CENTER,
+    [WKT1_CENTRAL_MERIDIAN],
     Angular,
     LON_0
 );
@@ -355,6 +377,16 @@ map!(
     LAT_1
 );
 
+// Lambert Conic Conformal 1SP: latitude of natural origin
+// is also emitted as lat_0
+map!(
+    LAT_0_LCC_1SP,
+    LATITUDE_OF_NATURAL_ORIGIN,
+    WKT1_LATITUDE_OF_ORIGIN,
+    Angular,
+    LAT_0
+);
+
 map!(
     SCALE_FACTOR_PSEUDO_STD_PARALLEL,
     SCALE_FACTOR_PSEUDO_STANDARD_PARALLEL,
@@ -375,6 +407,7 @@ map!(
     LAT_STD_PARALLEL,
     LATITUDE_STD_PARALLEL,
     WKT1_LATITUDE_OF_ORIGIN,
+    [WKT1_STANDARD_PARALLEL_1],
     Angular,
     LAT_TS
 );
@@ -383,6 +416,10 @@ map!(
     LONG_ORIGIN,
     LONGITUDE_OF_ORIGIN,
     WKT1_CENTRAL_MERIDIAN,
+    [
+        WKT1_LONGITUDE_OF_ORIGIN,
+        WKT1_STRAIGHT_VERTICAL_LONGITUDE_FROM_POLE
+    ],
     Angular,
     LON_0
 );
@@ -8,7 +8,8 @@ use nom::{
     branch::alt,
     bytes::complete::{is_not, tag},
     character::complete::{alpha1, alphanumeric1, char, digit1, multispace0},
-    combinator::{all_consuming, cut, fail, iterator, map, recognize},
+    combinator::{all_consuming, cut, iterator, map, recognize},
+    error::{ErrorKind, ParseError},
     multi::{fold_many0, many0_count},
     number::complete::recognize_float,
     sequence::{delimited, pair, preceded, terminated},
@@ -18,6 +19,7 @@ use nom::{
 use crate::errors::{Error, Result};
 use crate::log;
 
+use std::borrow::Cow;
 use std::fmt::Debug;
 
 /// Parsed WKT attributes
@@ -34,6 +36,33 @@ pub enum Attribute<'a, T> {
     Keyword(&'a str, T),
 }
 
+impl<'a, T> Attribute<'a, T> {
+    /// Return the string if the attribute is a quoted string
+    pub fn as_quoted(&self) -> Option<&'a str> {
+        match self {
+            Self::Quoted(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    /// Return the number token if the attribute is a number
+    pub fn as_number(&self) -> Option<&'a str> {
+        match self {
+            Self::Number(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    /// Return the keyword and the processor output if
+    /// the attribute is a keyword
+    pub fn as_keyword(&self) -> Option<(&'a str, &T)> {
+        match self {
+            Self::Keyword(k, v) => Some((k, v)),
+            _ => None,
+        }
+    }
+}
+
 /// A recursive token generator processor
 ///
 /// A `Processor` implements the grammar and produce the syntactic tree from
@@ -52,26 +81,169 @@ pub trait Processor<'a> {
     fn process<I>(&self, key: &'a str, depth: usize, attrs: I) -> Result<Self::Output, Self::Err>
     where
         I: Iterator<Item = Attribute<'a, Self::Output>>;
+
+    /// Return the error of the node `key` when processing
+    /// one of its child nodes failed with `err`
+    ///
+    /// The child error is returned unchanged by default.
+    fn nested_error(&self, _key: &'a str, err: Self::Err) -> Self::Err {
+        err
+    }
+}
+
+// Parser error: a syntax error or a processor error
+#[derive(Debug)]
+enum Failure<E> {
+    Syntax,
+    Process(E),
+}
+
+impl<I, E> ParseError<I> for Failure<E> {
+    fn from_error_kind(_input: I, _kind: ErrorKind) -> Self {
+        Self::Syntax
+    }
+
+    fn append(_input: I, _kind: ErrorKind, other: Self) -> Self {
+        other
+    }
+}
+
+type PResult<'a, O, E> = IResult<&'a str, O, Failure<E>>;
+
+// Return the processor error converted with `f`, or a parse error
+fn into_error<E>(err: nom::Err<Failure<E>>, f: impl FnOnce(E) -> Error) -> Error {
+    match err {
+        nom::Err::Error(Failure::Process(err)) | nom::Err::Failure(Failure::Process(err)) => f(err),
+        _ => Error::Parse,
+    }
 }
 
 /// Parse WKT string
 ///
 /// Parse a WKT string by applying a [`Processor`]
 /// to WKT nodes
+///
+/// Processor errors are logged and returned as [`Error::Parse`].
 pub fn parse<'a, P, O>(i: &'a str, p: &P) -> Result<O>
+where
+    P: Processor<'a, Output = O>,
+{
+    parse_with(i, p, |_| Error::Parse)
+}
+
+// Like `parse` but processor errors are returned
+pub(crate) fn parse_chained<'a, P, O>(i: &'a str, p: &P) -> Result<O>
+where
+    P: Processor<'a, Output = O, Err = Error>,
+{
+    parse_with(i, p, |err| err)
+}
+
+fn parse_with<'a, P, O>(i: &'a str, p: &P, f: impl FnOnce(P::Err) -> Error) -> Result<O>
 where
     P: Processor<'a, Output = O>,
 {
     all_consuming(|i: &'a str| object(i, p, 0))(i)
-        .map_err(|_| Error::Parse)
+        .map_err(|err| into_error(err, f))
+        .map(|(_, value)| match value {
+            Attribute::Keyword(_, out) => out,
+            _ => unreachable!(),
+        })
+}
+
+/// Parse the first WKT object of a string
+///
+/// Like [`parse`] but the input may be followed by
+/// any content, which is ignored.
+pub fn parse_fragment<'a, P>(input: &'a str, processor: &P) -> Result<P::Output>
+where
+    P: Processor<'a>,
+{
+    object(input, processor, 0)
+        .map_err(|err| into_error(err, |_| Error::Parse))
         .map(|(_, value)| match value {
             Attribute::Keyword(_, out) => out,
             _ => unreachable!(),
         })
 }
 
+/// Find the next WKT object of a string
+///
+/// Return `(matched_wkt, remaining)` where `matched_wkt` spans from
+/// the object keyword to its balanced closing bracket.
+/// Leading whitespaces are skipped. The content of the object is not
+/// validated.
+pub fn parse_next_wkt_object(input: &str) -> Result<(&str, &str)> {
+    let i = input.trim_start();
+    let (rest, _) = terminated(keyword, trim_left(char::<_, nom::error::Error<_>>('[')))(i)
+        .map_err(|_| Error::Parse)?;
+
+    // Scan from the opening bracket
+    let start = i.len() - rest.len() - 1;
+    let mut scanner = BracketScanner::default();
+    i.bytes()
+        .enumerate()
+        .skip(start)
+        .find(|(_, c)| scanner.scan(*c))
+        .map(|(pos, _)| i.split_at(pos + 1))
+        .ok_or(Error::Parse)
+}
+
+/// Track the nesting of brackets of a WKT object
+///
+/// Brackets within quoted strings are ignored. Bytes are
+/// scanned so that objects may be split across reads,
+/// delimiters are ASCII and never part of a multibyte
+/// UTF-8 sequence.
+#[derive(Default)]
+pub(crate) struct BracketScanner {
+    depth: usize,
+    quoted: bool,
+}
+
+impl BracketScanner {
+    /// Scan the next byte and return `true` if it closes
+    /// the outermost bracket
+    pub(crate) fn scan(&mut self, c: u8) -> bool {
+        match c {
+            // Escaped quotes toggle twice
+            b'"' => self.quoted = !self.quoted,
+            b'[' if !self.quoted => self.depth += 1,
+            b']' if !self.quoted && self.depth > 0 => {
+                self.depth -= 1;
+                return self.depth == 0;
+            }
+            _ => (),
+        }
+        false
+    }
+}
+
+/// Return the value of a quoted string
+///
+/// Quoted strings of the syntactic tree are kept escaped,
+/// embedded double quotes are doubled: `""` is replaced
+/// by `"`.
+pub fn unescape(s: &str) -> Cow<'_, str> {
+    if s.contains(r#""""#) {
+        Cow::Owned(s.replace(r#""""#, "\""))
+    } else {
+        Cow::Borrowed(s)
+    }
+}
+
+/// Return the keyword of the first WKT object of a string
+///
+/// Leading whitespaces are skipped, nothing after the
+/// keyword is scanned.
+pub fn first_keyword(input: &str) -> Option<&str> {
+    keyword::<nom::error::Error<_>>(input.trim_start())
+        .ok()
+        .map(|(_, key)| key)
+}
+
 // Single quote delimited string
-fn quoted_string<'a>(i: &'a str) -> IResult<&str, &str> {
+fn quoted_string<'a, E: ParseError<&'a str>>(i: &'a str) -> IResult<&'a str, &'a str, E> {
     delimited(
         char('"'),
         |s: &'a str| {
@@ -89,24 +261,25 @@ fn quoted_string<'a>(i: &'a str) -> IResult<&str, &str> {
 }
 
 // Number
-fn number(i: &str) -> IResult<&str, &str> {
+fn number<'a, E: ParseError<&'a str>>(i: &'a str) -> IResult<&'a str, &'a str, E> {
     alt((recognize_float, recognize(digit1)))(i)
 }
 
-fn keyword(i: &str) -> IResult<&str, &str> {
+fn keyword<'a, E: ParseError<&'a str>>(i: &'a str) -> IResult<&'a str, &'a str, E> {
     recognize(pair(
         alt((alpha1, tag("_"))),
         many0_count(alt((alphanumeric1, tag("_")))),
     ))(i)
 }
 
-fn log_failure<E: Debug, T>(_err: E) -> IResult<&'static str, T> {
-    log::error!("Wkt failure {_err:?}");
-    cut(fail)("")
+// Fail with a processor error
+fn process_failure<'a, T, E: Debug>(err: E) -> PResult<'a, T, E> {
+    log::error!("Wkt failure {err:?}");
+    Err(nom::Err::Failure(Failure::Process(err)))
 }
 
 // Process object attribute
-fn object<'a, P, O>(i: &'a str, p: &P, depth: usize) -> IResult<&'a str, Attribute<'a, O>>
+fn object<'a, P, O>(i: &'a str, p: &P, depth: usize) -> PResult<'a, Attribute<'a, O>, P::Err>
 where
     P: Processor<'a, Output = O>,
 {
@@ -132,7 +305,7 @@ fn attribute_list<'a, P, O>(
     p: &P,
     depth: usize,
     key: &'a str,
-) -> IResult<&'a str, Attribute<'a, O>>
+) -> PResult<'a, Attribute<'a, O>, P::Err>
 where
     P: Processor<'a, Output = O>,
 {
@@ -143,12 +316,15 @@ where
         preceded(trim_left(char(',')), |i: &'a str| attribute(i, p, depth)),
     );
 
-    match p.process(key, depth, std::iter::once(attr).chain(&mut it)) {
-        Ok(node) => {
-            let (rest, _) = it.finish()?;
-            Ok((rest, Attribute::Keyword(key, node)))
-        }
-        Err(err) => log_failure(err),
+    let result = p.process(key, depth, std::iter::once(attr).chain(&mut it));
+    match it.finish() {
+        // Failure of a child node
+        Err(nom::Err::Failure(Failure::Process(err))) => process_failure(p.nested_error(key, err)),
+        Err(err) => Err(err),
+        Ok((rest, _)) => match result {
+            Ok(node) => Ok((rest, Attribute::Keyword(key, node))),
+            Err(err) => process_failure(err),
+        },
     }
 }
 
@@ -157,15 +333,21 @@ where
 //     keyword attibute_list
 //   | quoted_string
 //   | number
-fn attribute<'a, P, O>(i: &'a str, p: &P, depth: usize) -> IResult<&'a str, Attribute<'a, O>>
+fn attribute<'a, P, O>(i: &'a str, p: &P, depth: usize) -> PResult<'a, Attribute<'a, O>, P::Err>
 where
     P: Processor<'a, Output = O>,
 {
     let i = i.trim_start();
-    object(i, p, depth + 1)
-        .or_else(|_| map(quoted_string, |s| Attribute::Quoted(s))(i))
-        .or_else(|_| map(number, |n| Attribute::Number(n))(i))
-        .or_else(|_| map(keyword, |l| Attribute::Label(l))(i))
+    match object(i, p, depth + 1) {
+        // Failures are not recoverable
+        Err(nom::Err::Error(_)) => (),
+        result => return result,
+    }
+    alt((
+        map(quoted_string, |s| Attribute::Quoted(s)),
+        map(number, |n| Attribute::Number(n)),
+        map(keyword, |l| Attribute::Label(l)),
+    ))(i)
 }
 
 // Trim whitespaces
@@ -189,27 +371,34 @@ mod tests {
     use super::*;
     use crate::errors::{Error, Result};
 
+    type E<'a> = nom::error::Error<&'a str>;
+
     #[test]
     fn parse_quoted_str() {
-        assert_eq!(quoted_string(r#""""#), Ok(("", "")));
-        assert_eq!(quoted_string(r#""foo""bar""#), Ok(("", r#"foo""bar"#)));
-        assert_eq!(quoted_string(r#""foobar" baz"#), Ok((" baz", r#"foobar"#)));
+        assert_eq!(quoted_string::<E>(r#""""#), Ok(("", "")));
+        assert_eq!(quoted_string::<E>(r#""foo""bar""#), Ok(("", r#"foo""bar"#)));
+        assert_eq!(
+            quoted_string::<E>(r#""foobar" baz"#),
+            Ok((" baz", r#"foobar"#))
+        );
+        assert_eq!(unescape(r#"foo""bar"#), r#"foo"bar"#);
+        assert!(matches!(unescape("foobar"), Cow::Borrowed("foobar")));
     }
 
     #[test]
     fn parse_number() {
-        assert_eq!(number("1234.56"), Ok(("", "1234.56")));
-        assert_eq!(number("1234"), Ok(("", "1234")));
-        assert!(number("baz").is_err());
+        assert_eq!(number::<E>("1234.56"), Ok(("", "1234.56")));
+        assert_eq!(number::<E>("1234"), Ok(("", "1234")));
+        assert!(number::<E>("baz").is_err());
     }
 
     #[test]
     fn parse_keyword() {
-        assert_eq!(keyword("KEY"), Ok(("", "KEY")));
-        assert_eq!(keyword("KEY12_"), Ok(("", "KEY12_")));
-        assert_eq!(keyword("_KEY1"), Ok(("", "_KEY1")));
-        assert_eq!(keyword("_1KEY"), Ok(("", "_1KEY")));
-        assert!(keyword("1KEY").is_err());
+        assert_eq!(keyword::<E>("KEY"), Ok(("", "KEY")));
+        assert_eq!(keyword::<E>("KEY12_"), Ok(("", "KEY12_")));
+        assert_eq!(keyword::<E>("_KEY1"), Ok(("", "_KEY1")));
+        assert_eq!(keyword::<E>("_1KEY"), Ok(("", "_1KEY")));
+        assert!(keyword::<E>("1KEY").is_err());
     }
 
     #[derive(Debug, PartialEq)]
@@ -237,7 +426,7 @@ mod tests {
 
     #[test]
     fn parse_wkt() {
-        let wkt = parse(r#"FOO["foo", BAR["bar"], baz]"#, &Builder::default()).unwrap();
+        let wkt = parse(r#"FOO["foo", BAR["bar"], baz]"#, &Builder).unwrap();
 
         assert_eq!(
             wkt,
@@ -252,9 +441,101 @@ mod tests {
         );
     }
 
+    #[test]
+    fn attribute_extractors() {
+        let Node(_, attrs) = parse(r#"FOO["foo", 12.5, BAR["bar"], baz]"#, &Builder).unwrap();
+
+        assert_eq!(
+            attrs.iter().map(|a| a.as_quoted()).collect::<Vec<_>>(),
+            [Some("foo"), None, None, None]
+        );
+        assert_eq!(
+            attrs.iter().map(|a| a.as_number()).collect::<Vec<_>>(),
+            [None, Some("12.5"), None, None]
+        );
+        assert_eq!(
+            attrs.iter().find_map(|a| a.as_keyword()),
+            Some(("BAR", &Node("BAR", vec![Attribute::Quoted("bar")])))
+        );
+        assert!(attrs[3].as_keyword().is_none());
+    }
+
     #[test]
     fn parse_failure() {
-        let mut wkt = Builder::default();
-        assert!(parse(r#"FOO["foo", BAR["bar"]"#, &mut wkt).is_err());
+        assert!(parse(r#"FOO["foo", BAR["bar"]"#, &Builder).is_err());
+    }
+
+    // Processor with its own error type
+    struct Rejecting;
+
+    impl<'a> Processor<'a> for Rejecting {
+        type Err = &'static str;
+        type Output = ();
+
+        fn process<I>(&self, key: &'a str, _depth: usize, attrs: I) -> Result<(), Self::Err>
+        where
+            I: Iterator<Item = Attribute<'a, ()>>,
+        {
+            attrs.for_each(drop);
+            match key {
+                "BAR" => Err("Rejected BAR"),
+                _ => Ok(()),
+            }
+        }
+    }
+
+    #[test]
+    fn parse_processor_error() {
+        assert!(parse(r#"FOO["foo", BAZ["baz"]]"#, &Rejecting).is_ok());
+        assert!(matches!(
+            parse(r#"FOO["foo", BAR["bar"]]"#, &Rejecting),
+            Err(Error::Parse)
+        ));
+        assert!(matches!(
+            parse_fragment(r#"BAR["bar"] baz"#, &Rejecting),
+            Err(Error::Parse)
+        ));
+    }
+
+    #[test]
+    fn parse_wkt_fragment() {
+        let input = r#"FOO["foo", BAR["bar"]]</gml:wkt></gml:crs>"#;
+        assert!(parse(input, &Builder).is_err());
+        assert_eq!(
+            parse_fragment(input, &Builder).unwrap(),
+            Node(
+                "FOO",
+                vec![
+                    Attribute::Quoted("foo"),
+                    Attribute::Keyword("BAR", Node("BAR", vec![Attribute::Quoted("bar")])),
+                ],
+            ),
+        );
+        assert!(parse_fragment(r#"FOO["foo", BAR["bar"]"#, &Builder).is_err());
+        assert!(parse_fragment(r#"<gml:wkt>FOO["foo"]"#, &Builder).is_err());
+    }
+
+    #[test]
+    fn find_next_wkt_object() {
+        let input = r#"  FOO["a]b""[", BAR["bar"]] BAZ[1]<gml:wkt/>"#;
+        let (wkt, rest) = parse_next_wkt_object(input).unwrap();
+        assert_eq!(wkt, r#"FOO["a]b""[", BAR["bar"]]"#);
+        assert_eq!(rest, r#" BAZ[1]<gml:wkt/>"#);
+
+        let (wkt, rest) = parse_next_wkt_object(rest).unwrap();
+        assert_eq!(wkt, "BAZ[1]");
+        assert_eq!(rest, "<gml:wkt/>");
+
+        assert!(parse_next_wkt_object(rest).is_err());
+        assert!(parse_next_wkt_object(r#"FOO["foo", BAR["bar"]"#).is_err());
+        assert!(parse_next_wkt_object(r#"FOO["foo]"#).is_err());
+    }
+
+    #[test]
+    fn find_first_keyword() {
+        assert_eq!(first_keyword(" \n GEOGCS[\"WGS 84\"]"), Some("GEOGCS"));
+        assert_eq!(first_keyword("BASE_GEOGCRS"), Some("BASE_GEOGCRS"));
+        assert_eq!(first_keyword(r#"["foo"]"#), None);
+        assert_eq!(first_keyword(""), None);
     }
 }
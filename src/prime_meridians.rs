@@ -0,0 +1,62 @@
+//!
+//! Prime meridians
+//!
+//! Well known prime meridians as `(wkt name, longitude in degrees, proj name)`.
+//!
+//! From proj library: <https://github.com/OSGeo/PROJ>  (/src/datums.cpp)
+//!
+
+#[rustfmt::skip]
+pub const KNOWN_PRIME_MERIDIANS: &[(&str, f64, &str)] = &[
+    ("Greenwich",  0.0,              "greenwich"),
+    ("Lisbon",     -9.131906111111,  "lisbon"),
+    ("Paris",      2.337229166667,   "paris"),
+    ("Bogota",     -74.080916666667, "bogota"),
+    ("Madrid",     -3.687938888889,  "madrid"),
+    ("Rome",       12.452333333333,  "rome"),
+    ("Bern",       7.439583333333,   "bern"),
+    ("Jakarta",    106.807719444444, "jakarta"),
+    ("Ferro",      -17.666666666667, "ferro"),
+    ("Brussels",   4.367975,         "brussels"),
+    ("Stockholm",  18.058277777778,  "stockholm"),
+    ("Athens",     23.7163375,       "athens"),
+    ("Oslo",       10.722916666667,  "oslo"),
+    ("Copenhagen", 12.57788,         "copenhagen"),
+];
+
+// Tolerance in degrees when checking the longitude
+// of a named prime meridian
+const LONGITUDE_TOLERANCE: f64 = 1e-6;
+
+/// Retrieve the proj name of a known prime meridian
+///
+/// The name is compared ignoring case and the longitude (in degrees)
+/// must match the known value.
+pub fn find_prime_meridian(name: &str, longitude: f64) -> Option<&'static str> {
+    KNOWN_PRIME_MERIDIANS
+        .iter()
+        .find(|(wkt_name, lon, _)| {
+            wkt_name.eq_ignore_ascii_case(name) && (lon - longitude).abs() < LONGITUDE_TOLERANCE
+        })
+        .map(|(_, _, proj_name)| *proj_name)
+}
+
+// ==============================
+//  Tests
+// ==============================
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn prime_meridian_lookup() {
+        assert_eq!(find_prime_meridian("Paris", 2.33722917), Some("paris"));
+        assert_eq!(
+            find_prime_meridian("ferro", -17.6666666666667),
+            Some("ferro")
+        );
+        // Wrong unit
+        assert!(find_prime_meridian("Paris", 2.5969213).is_none());
+        assert!(find_prime_meridian("Unknown", 2.33722917).is_none());
+    }
+}
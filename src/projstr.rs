@@ -3,12 +3,233 @@
 //! to projstring
 //!
 use crate::builder::{parse_number, Node};
+use crate::datums::{find_datum_preset, DatumShift};
+use crate::ellipsoids::{find_ellipsoid, find_ellipsoid_by_code};
 use crate::errors::{Error, Result};
-use crate::methods::{find_method_mapping, MethodMapping};
+use crate::geoids::{find_geoid_grid, find_geoid_model_grid};
+use crate::io_utils::Utf8Writer;
+use crate::methods::{find_method_mapping, oriented_method_mapping, MethodMapping};
 use crate::model::*;
+use crate::params::ParamMapping;
+use crate::parser::unescape;
+use crate::prime_meridians::find_prime_meridian;
+use crate::report::Report;
 
+use std::borrow::Cow;
 use std::io::Write;
 
+/// Options of the WKT to proj string conversion
+///
+/// Example:
+///
+/// ```
+/// use proj4wkt::{wkt_to_projstring_with_options, ConversionOptions};
+///
+/// let wkt = concat!(
+///     r#"GEOGCS["NAD83",DATUM["North_American_Datum_1983","#,
+///     r#"SPHEROID["GRS 1980",6378137,298.257222101]],"#,
+///     r#"PRIMEM["Greenwich",0],UNIT["degree",0.0174532925199433]]"#,
+/// );
+///
+/// let options = ConversionOptions::new()
+///     .suppress_towgs84(true)
+///     .use_ellipsoid_names(true);
+///
+/// assert_eq!(
+///     wkt_to_projstring_with_options(wkt, options).unwrap(),
+///     "+proj=longlat +ellps=GRS80",
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConversionOptions {
+    suppress_towgs84: bool,
+    prefer_etmerc: bool,
+    always_output_units: bool,
+    strict_validation: bool,
+    use_datum_names: bool,
+    use_ellipsoid_names: bool,
+    strict_towgs84: bool,
+    assume_null_towgs84: bool,
+    precision: Option<usize>,
+    collapse_wgs84: bool,
+    redundant_ellipsoid: bool,
+    prefer_init: bool,
+    sphere_radius: Vec<String>,
+}
+
+impl Default for ConversionOptions {
+    fn default() -> Self {
+        Self {
+            suppress_towgs84: false,
+            prefer_etmerc: false,
+            always_output_units: true,
+            strict_validation: false,
+            use_datum_names: false,
+            use_ellipsoid_names: false,
+            strict_towgs84: false,
+            assume_null_towgs84: true,
+            precision: None,
+            collapse_wgs84: false,
+            redundant_ellipsoid: false,
+            prefer_init: false,
+            sphere_radius: Vec::new(),
+        }
+    }
+}
+
+impl ConversionOptions {
+    /// Create options with default values
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Do not emit `+towgs84=` parameters
+    ///
+    /// Disabled by default.
+    pub fn suppress_towgs84(mut self, value: bool) -> Self {
+        self.suppress_towgs84 = value;
+        self
+    }
+
+    /// Emit `+proj=etmerc` instead of `+proj=tmerc`
+    ///
+    /// Disabled by default.
+    pub fn prefer_etmerc(mut self, value: bool) -> Self {
+        self.prefer_etmerc = value;
+        self
+    }
+
+    /// Emit `+units=m` even if the unit is the metre
+    ///
+    /// Enabled by default.
+    pub fn always_output_units(mut self, value: bool) -> Self {
+        self.always_output_units = value;
+        self
+    }
+
+    /// Return an error instead of a warning when a projection
+    /// parameter is physically invalid, i.e. a latitude beyond ±90,
+    /// a longitude beyond ±360 or a non positive scale factor, or
+    /// when a required projection parameter is missing
+    ///
+    /// Implausible values, such as a scale factor far from 1,
+    /// still only raise a warning. Disabled by default.
+    pub fn strict_validation(mut self, value: bool) -> Self {
+        self.strict_validation = value;
+        self
+    }
+
+    /// Emit `+datum=` for well known datums with no
+    /// explicit shift parameters
+    ///
+    /// Disabled by default.
+    pub fn use_datum_names(mut self, value: bool) -> Self {
+        self.use_datum_names = value;
+        self
+    }
+
+    /// Emit `+ellps=` instead of the ellipsoid parameters
+    /// for well known ellipsoids
+    ///
+    /// Disabled by default.
+    pub fn use_ellipsoid_names(mut self, value: bool) -> Self {
+        self.use_ellipsoid_names = value;
+        self
+    }
+
+    /// Warn when a datum has no `TOWGS84` node and no known
+    /// shift, a null shift is still emitted
+    ///
+    /// Disabled by default.
+    pub fn strict_towgs84(mut self, value: bool) -> Self {
+        self.strict_towgs84 = value;
+        self
+    }
+
+    /// Emit a null `+towgs84=` shift when a datum has no `TOWGS84`
+    /// node and no known shift, i.e assume WGS84 compatibility
+    ///
+    /// When disabled, no shift is emitted and proj treats the
+    /// datum as distinct from WGS84. This avoids the metric
+    /// error of the implicit equivalence of GRS80 based datums
+    /// such as NAD83(2011).
+    ///
+    /// Enabled by default.
+    pub fn assume_null_towgs84(mut self, value: bool) -> Self {
+        self.assume_null_towgs84 = value;
+        self
+    }
+
+    /// Round emitted projection parameters to `digits` significant digits
+    ///
+    /// Ellipsoid, prime meridian, datum shift and unit values are never
+    /// rounded. By default, values are emitted verbatim.
+    pub fn precision(mut self, digits: usize) -> Self {
+        self.precision = Some(digits.max(1));
+        self
+    }
+
+    /// Emit `+datum=WGS84` instead of the ellipsoid and
+    /// null shift parameters when the datum is unambiguously WGS84
+    ///
+    /// Disabled by default.
+    pub fn collapse_wgs84(mut self, value: bool) -> Self {
+        self.collapse_wgs84 = value;
+        self
+    }
+
+    /// Also emit the ellipsoid parameters when a `+datum=`
+    /// is emitted
+    ///
+    /// Disabled by default.
+    pub fn redundant_ellipsoid(mut self, value: bool) -> Self {
+        self.redundant_ellipsoid = value;
+        self
+    }
+
+    /// Emit `+init=EPSG:<code>` instead of the crs parameters
+    /// when the crs is identified by an EPSG code
+    ///
+    /// Using `+init` requires a proj database. Disabled by default.
+    pub fn prefer_init(mut self, value: bool) -> Self {
+        self.prefer_init = value;
+        self
+    }
+
+    /// Emit the mean radius of the ellipsoid as `+R=` instead of
+    /// the ellipsoid and datum shift parameters for the listed
+    /// proj projections
+    ///
+    /// See [`SPHERICAL_PROJECTIONS`] for the projections implemented
+    /// on the sphere only. Disabled by default.
+    pub fn sphere_radius<I>(mut self, projections: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        self.sphere_radius = projections
+            .into_iter()
+            .map(|p| p.as_ref().to_string())
+            .collect();
+        self
+    }
+}
+
+/// Projections implemented by proj on the sphere only
+///
+/// Mollweide, Wagner IV and V, Robinson, Natural Earth,
+/// Natural Earth II and Gall stereographic.
+/// To be used with [`ConversionOptions::sphere_radius`].
+pub const SPHERICAL_PROJECTIONS: &[&str] = &[
+    "moll",
+    "wag4",
+    "wag5",
+    "robin",
+    "natearth",
+    "natearth2",
+    "gall",
+];
+
 /// WKT Formatter that output to [`Write`]
 ///
 /// A formatter will transform a WKT CRS syntactic
@@ -36,77 +257,427 @@ use std::io::Write;
 /// let mut buf = String::new();
 /// Builder::new()
 ///    .parse(wkt_string)
-///    .and_then(|node| Formatter::new(unsafe { buf.as_mut_vec() }).format(&node))
+///    .and_then(|node| Formatter::new_string(&mut buf).format(&node))
 ///    .unwrap()
 /// ```
-///
-
-pub struct Formatter<T: Write> {
+pub struct Formatter<T> {
     w: T,
+    report: Report,
+    options: ConversionOptions,
 }
 
-impl<T: Write> Formatter<T> {
+impl<T> Formatter<T> {
     /// Create a new Formatter
     pub fn new(w: T) -> Self {
-        Self { w }
+        Self {
+            w,
+            report: Report::default(),
+            options: ConversionOptions::default(),
+        }
+    }
+
+    /// Set the conversion options
+    pub fn options(mut self, options: ConversionOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    // Create a formatter with the same options
+    // writing to `w`
+    fn with_writer<W>(&self, w: W) -> Formatter<W> {
+        Formatter {
+            w,
+            report: Report::default(),
+            options: self.options.clone(),
+        }
+    }
+
+    /// Return the report of the last conversion
+    pub fn report(&self) -> &Report {
+        &self.report
     }
 
+    /// Consume the formatter and return the report
+    /// of the last conversion
+    pub fn into_report(self) -> Report {
+        self.report
+    }
+}
+
+impl<T: Write> Formatter<T> {
     /// Format a `Processor` root node output to
     /// a proj4 string
     pub fn format(&mut self, node: &Node) -> Result<()> {
+        self.emit(node)
+    }
+
+    /// Format the base geographic crs of a projected
+    /// `Processor` root node output as a `+proj=longlat`
+    /// proj4 string
+    pub fn format_base_geographic(&mut self, node: &Node) -> Result<()> {
+        self.emit_base_geographic(node)
+    }
+
+    /// Format the ellipsoid and the datum shift of a
+    /// [`Datum`] without the `+proj` parameter
+    pub fn format_datum(&mut self, datum: &Datum) -> Result<()> {
+        self.format_component(|f| f.add_datum(datum))
+    }
+
+    /// Format the axes of an [`Ellipsoid`] without
+    /// the `+proj` parameter
+    pub fn format_ellipsoid(&mut self, ellps: &Ellipsoid) -> Result<()> {
+        self.format_component(|f| f.add_ellipsoid(ellps))
+    }
+
+    // Format a crs component, parameters are written
+    // without the separator of the leading parameter
+    fn format_component<F>(&mut self, f: F) -> Result<()>
+    where
+        F: FnOnce(&mut Formatter<Utf8Writer<'_>>) -> Result<()>,
+    {
+        let mut buf = String::new();
+        let mut formatter = self.with_writer(Utf8Writer(&mut buf));
+        let result = f(&mut formatter);
+        self.report = formatter.into_report();
+        result?;
+        self.w.write_all(buf.trim_start().as_bytes())?;
+        Ok(())
+    }
+
+    /// Format a `Processor` root node output to a new
+    /// proj4 string and return it with the kind of the CRS
+    ///
+    /// The formatter options apply but nothing is written
+    /// to the underlying writer.
+    pub fn format_with_kind(&mut self, node: &Node) -> Result<(CrsKind, String)> {
+        let kind = node
+            .crs_kind()
+            .ok_or_else(|| Error::Wkt(format!("Cannot create projstring from {node:?}").into()))?;
+        let mut buf = String::new();
+        let mut formatter = self.with_writer(Utf8Writer(&mut buf));
+        let result = formatter.format(node);
+        self.report = formatter.into_report();
+        result.and(Ok((kind, buf)))
+    }
+}
+
+/// Output of the proj parameters emitted by a [`Formatter`]
+pub trait Sink<'a> {
+    /// Emit a `+key=value` parameter or a `+key` flag
+    fn param(&mut self, key: &'static str, value: Option<Cow<'a, str>>) -> Result<()>;
+}
+
+impl<'a, W: Write> Sink<'a> for W {
+    fn param(&mut self, key: &'static str, value: Option<Cow<'a, str>>) -> Result<()> {
+        // The projection or the init file always comes first
+        if key != "proj" && key != "init" {
+            self.write_all(b" ")?;
+        }
+        match value {
+            Some(v) => write!(self, "+{key}={v}")?,
+            None => write!(self, "+{key}")?,
+        }
+        Ok(())
+    }
+}
+
+impl<'a, T: Sink<'a>> Formatter<T> {
+    fn emit(&mut self, node: &Node<'a>) -> Result<()> {
+        self.report = Report::default();
+        if self.options.prefer_init {
+            if let Some(auth) = node.authority().filter(|auth| auth.name == "EPSG") {
+                return self
+                    .w
+                    .param("init", Some(format!("EPSG:{}", auth.code).into()));
+            }
+        }
         match node {
             Node::GEOGCRS(cs) => self.add_geogcs(cs),
+            Node::GEOCCRS(cs) => self.add_geoccs(cs),
             Node::PROJCRS(cs) => self.add_projcs(cs),
-            Node::COMPOUNDCRS(crs) => match &crs.h_crs {
-                Horizontalcrs::Projcs(cs) => self.add_projcs(cs),
-                Horizontalcrs::Geogcs(cs) => self.add_geogcs(cs),
-            },
+            Node::COMPOUNDCRS(crs) => self.add_compoundcrs(crs),
+            Node::COORDINATEMETADATA(md) => {
+                // Coordinate epoch has no proj equivalent
+                crate::log::debug!("Coordinate epoch: {:?}", md.epoch);
+                self.emit(&md.crs)
+            }
+            Node::DERIVEDPROJCRS(crs) => {
+                let result = self.emit(&crs.base);
+                self.report
+                    .diagnostics
+                    .warn(format!("Deriving conversion of '{}' was ignored", crs.name));
+                result
+            }
+            Node::OTHER("ENGCRS") => Err(Error::Wkt(
+                "Engineering CRS is not supported for proj string conversion".into(),
+            )),
             _ => Err(Error::Wkt(
                 format!("Cannot create projstring from {node:?}").into(),
             )),
         }
     }
 
-    #[inline]
-    fn write_str(&mut self, s: &str) -> std::io::Result<usize> {
-        self.w.write(s.as_bytes())
+    fn emit_base_geographic(&mut self, node: &Node<'a>) -> Result<()> {
+        self.report = Report::default();
+        match node.base_geographic() {
+            Some(cs) => self.add_geogcs(cs),
+            None => Err(Error::Wkt("Not a projected crs".into())),
+        }
     }
 
-    fn add_geogcs(&mut self, geogcs: &Geogcs) -> Result<()> {
-        self.write_str("+proj=longlat")?;
-        self.add_datum(&geogcs.datum)
+    // Check that a projection parameter is within its valid and
+    // plausible ranges
+    fn check_range(&mut self, name: &str, v: f64) -> Result<()> {
+        let out_of_range = find_param_ranges(name)
+            .find(|(_, min, max, _)| !(min - RANGE_TOLERANCE..=max + RANGE_TOLERANCE).contains(&v));
+        if let Some((_, _, _, valid)) = out_of_range {
+            let msg = format!("Parameter out of range: {name}={v}");
+            if *valid && self.options.strict_validation {
+                return Err(Error::Wkt(msg.into()));
+            }
+            self.report.diagnostics.warn(msg);
+        }
+        Ok(())
     }
 
-    fn add_datum(&mut self, datum: &Datum) -> Result<()> {
-        self.add_ellipsoid(&datum.ellipsoid)?;
-        if datum.to_wgs84.is_empty() {
-            // Assume WGS84 or GRS80 compatible
-            self.write_str(" +towgs84=0,0,0,0,0,0,0")?;
+    // Round a projection parameter to the configured precision
+    fn round(&self, v: f64) -> Result<f64> {
+        match self.options.precision {
+            Some(digits) => parse_number(&format!("{:.*e}", digits - 1, v)),
+            None => Ok(round_computed(v)),
+        }
+    }
+
+    // Write a ` +name=value` projection parameter from a computed value
+    fn write_param(&mut self, name: &'static str, v: f64) -> Result<()> {
+        self.check_range(name, v)?;
+        let v = self.round(v)?;
+        self.w.param(name, Some(v.to_string().into()))
+    }
+
+    // Write a ` +name=value` token from a static value
+    fn write_param_static(&mut self, name: &'static str, s: &'static str) -> Result<()> {
+        self.w.param(name, Some(s.into()))
+    }
+
+    // Write a ` +name=value` projection parameter from a parsed token,
+    // the value is emitted verbatim if no precision is set
+    fn write_param_str(&mut self, name: &'static str, s: &'a str) -> Result<()> {
+        if self.options.precision.is_some() {
+            self.write_param(name, parse_number(s)?)
         } else {
-            self.write_str(" +towgs84=")?;
-            datum.to_wgs84.iter().try_fold("", |sep, n| {
-                write!(self.w, "{sep}{n}").map_err(Error::from).and(Ok(","))
-            })?;
+            if find_param_ranges(name).next().is_some() {
+                self.check_range(name, parse_number(s)?)?;
+            }
+            self.w.param(name, Some(s.into()))
+        }
+    }
+
+    // Write a ` +name=value` geodetic value from a computed value,
+    // geodetic values are never rounded
+    fn write_value(&mut self, name: &'static str, v: f64) -> Result<()> {
+        self.w
+            .param(name, Some(round_computed(v).to_string().into()))
+    }
+
+    // Write a ` +name=value` geodetic value from a parsed token
+    fn write_value_str(&mut self, name: &'static str, s: &'a str) -> Result<()> {
+        self.w.param(name, Some(s.into()))
+    }
+
+    // Write a ` +name=v1,v2,...` geodetic value from parsed tokens
+    fn write_param_list<'s>(
+        &mut self,
+        name: &'static str,
+        values: impl IntoIterator<Item = &'s str>,
+    ) -> Result<()> {
+        let mut list = String::new();
+        values.into_iter().fold("", |sep, s| {
+            list.push_str(sep);
+            list.push_str(s);
+            ","
+        });
+        self.w.param(name, Some(list.into()))
+    }
+
+    // Write auxiliary `+key=value` or `+key` tokens
+    fn write_aux(&mut self, aux: &'static str) -> Result<()> {
+        aux.split_whitespace().try_for_each(|token| {
+            let token = token.trim_start_matches('+');
+            match token.split_once('=') {
+                Some((key, value)) => self.write_param_static(key, value),
+                None => self.w.param(token, None),
+            }
+        })
+    }
+
+    fn add_geogcs(&mut self, geogcs: &Geogcs<'a>) -> Result<()> {
+        self.write_param_static("proj", "longlat")?;
+        self.add_datum(&geogcs.datum)?;
+        self.add_prime_meridian(geogcs)?;
+        self.add_lon_wrap(geogcs.usage.as_ref())
+    }
+
+    fn add_lon_wrap(&mut self, usage: Option<&UsageInfo>) -> Result<()> {
+        if matches!(usage, Some(u) if u.crosses_antimeridian()) {
+            self.write_param_static("lon_wrap", "180")?;
+        }
+        Ok(())
+    }
+
+    fn add_compoundcrs(&mut self, crs: &Compoundcrs<'a>) -> Result<()> {
+        match &crs.h_crs {
+            Horizontalcrs::Projcs(cs) => self.add_projcs(cs)?,
+            Horizontalcrs::Geogcs(cs) => self.add_geogcs(cs)?,
+        }
+        self.add_verticalcrs(&crs.v_crs)
+    }
+
+    fn add_verticalcrs(&mut self, vcrs: &Verticalcrs<'a>) -> Result<()> {
+        if let Some(geoid) = &vcrs.geoid_model {
+            // Geoid model names are not grid files
+            match find_geoid_model_grid(geoid.name) {
+                Some(grid) => self.write_param_static("geoidgrids", grid)?,
+                None => self.report.diagnostics.warn(format!(
+                    "No geoid grid known for geoid model '{}'",
+                    geoid.name
+                )),
+            }
+        } else if let Some(auth) = vcrs.authority.as_ref().filter(|a| a.name == "EPSG") {
+            match find_geoid_grid(auth.code) {
+                Some(grid) => self.write_param_static("geoidgrids", grid)?,
+                None => self.report.diagnostics.warn(format!(
+                    "No geoid grid known for vertical crs EPSG:{}",
+                    auth.code
+                )),
+            }
+        }
+        match &vcrs.unit {
+            Some(unit) if unit.factor != 1.0 => match proj_linear_unit(unit.factor) {
+                Some(name) => self.write_param_static("vunits", name)?,
+                None => self.write_value("vto_meter", unit.factor)?,
+            },
+            _ => (),
+        }
+        Ok(())
+    }
+
+    fn add_geoccs(&mut self, geoccs: &Geogcs<'a>) -> Result<()> {
+        self.write_param_static("proj", "geocent")?;
+        self.add_datum(&geoccs.datum)?;
+        self.add_prime_meridian(geoccs)?;
+        self.write_linear_unit(geoccs.unit.as_ref())
+    }
+
+    fn add_prime_meridian(&mut self, geogcs: &Geogcs<'a>) -> Result<()> {
+        let pm = geogcs.prime_meridian_longitude();
+        if pm != 0.0 {
+            match geogcs
+                .prime_meridian
+                .as_ref()
+                .and_then(|p| find_prime_meridian(p.name, pm))
+            {
+                Some(name) => self.write_param_static("pm", name)?,
+                None => self.write_value("pm", pm)?,
+            }
+        }
+        Ok(())
+    }
+
+    fn add_datum(&mut self, datum: &Datum<'a>) -> Result<()> {
+        if self.options.collapse_wgs84 && datum.is_wgs84_fingerprint() {
+            self.write_param_static("datum", "WGS84")?;
+            if self.options.redundant_ellipsoid {
+                self.add_ellipsoid(&datum.ellipsoid)?;
+            }
+            return Ok(());
+        }
+        if self.options.use_datum_names && datum.nadgrids.is_none() && !datum.has_explicit_towgs84 {
+            if let Some(preset) = find_datum_preset(datum.name) {
+                self.write_param_static("datum", preset.proj_name)?;
+                if self.options.redundant_ellipsoid {
+                    self.add_ellipsoid(&datum.ellipsoid)?;
+                }
+                return Ok(());
+            }
+        }
+        self.add_ellipsoid(&datum.ellipsoid)?;
+        if let Some(grids) = datum.nadgrids {
+            // Grid based shift takes precedence over TOWGS84
+            return self.w.param("nadgrids", Some(unescape(grids)));
+        }
+        if datum.has_explicit_towgs84 {
+            // Explicit shift takes precedence over the datum presets
+            if !self.options.suppress_towgs84 {
+                self.write_param_list("towgs84", &datum.to_wgs84)?;
+            }
+            return Ok(());
+        }
+        match find_datum_preset(datum.name).map(|preset| &preset.shift) {
+            Some(DatumShift::Nadgrids(grids)) => self.write_param_static("nadgrids", grids)?,
+            _ if self.options.suppress_towgs84 => (),
+            Some(DatumShift::ToWgs84(params)) => {
+                self.write_param_list("towgs84", params.split(','))?
+            }
+            None if self.options.assume_null_towgs84 => {
+                if self.options.strict_towgs84 {
+                    self.report.diagnostics.warn(format!(
+                        "Unknown datum shift for '{}', assuming WGS84 compatible",
+                        datum.name
+                    ));
+                }
+                // Assume WGS84 or GRS80 compatible
+                self.write_param_static("towgs84", "0,0,0,0,0,0,0")?;
+            }
+            None => (),
         }
         Ok(())
     }
 
     // Since we do not use database, output ellipsoid parameters
     // and get rid of ellipsoid name and authority
-    fn add_ellipsoid(&mut self, ellps: &Ellipsoid) -> Result<()> {
+    fn add_ellipsoid(&mut self, ellps: &Ellipsoid<'a>) -> Result<()> {
         let a = ellps.a;
         let rf = ellps.rf;
+        if self.options.use_ellipsoid_names {
+            let factor = match &ellps.unit {
+                Some(unit) if unit.unit_type == UnitType::Linear => Some(unit.factor),
+                Some(_) => None,
+                None => Some(1.0),
+            };
+            if let Some(factor) = factor {
+                let (a, rf) = (parse_number(a)? * factor, parse_number(rf)?);
+                // The EPSG code is only trusted if the parameters agree
+                let name = match &ellps.authority {
+                    Some(auth) if auth.name.eq_ignore_ascii_case("EPSG") => {
+                        find_ellipsoid_by_code(auth.code, a, rf)
+                    }
+                    _ => None,
+                };
+                if let Some(name) = name.or_else(|| find_ellipsoid(a, rf)) {
+                    return self.write_param_static("ellps", name);
+                }
+            }
+        }
+        // A null inverse flattening defines a sphere
+        let sphere = parse_number(rf)? == 0.0;
         // Check units
         if let Some(unit) = &ellps.unit {
             match unit.unit_type {
                 UnitType::Linear => {
                     if unit.factor != 1.0 {
                         // Convert to meter
+                        // Inverse flattening is dimensionless
                         let a = parse_number(a)? * unit.factor;
-                        let rf = parse_number(rf)? * unit.factor;
-                        write!(self.w, " +a={a} +rf={rf}")?;
+                        self.write_value("a", a)?;
+                        if sphere {
+                            self.write_value("b", a)?;
+                        } else {
+                            self.write_value_str("rf", rf)?;
+                        }
                     } else {
-                        write!(self.w, " +a={a} +rf={rf}")?;
+                        self.write_semi_axes(a, rf, sphere)?;
                     }
                 }
                 _ => {
@@ -117,29 +688,87 @@ impl<T: Write> Formatter<T> {
                 }
             }
         } else {
-            write!(self.w, " +a={a} +rf={rf}")?;
+            self.write_semi_axes(a, rf, sphere)?;
         }
         Ok(())
     }
 
-    fn add_projcs(&mut self, projcs: &Projcs) -> Result<()> {
-        // Check the projection
-        if let Some(mapping) = find_method_mapping(&projcs.projection.method) {
-            write!(self.w, "+proj={}", mapping.proj_name())?;
+    // Write the mean radius `(2a + b) / 3` of the ellipsoid
+    fn add_sphere_radius(&mut self, ellps: &Ellipsoid<'a>) -> Result<()> {
+        let factor = match &ellps.unit {
+            Some(unit) if unit.unit_type == UnitType::Linear => unit.factor,
+            Some(unit) => {
+                return Err(Error::Wkt(
+                    format!("Unexpected {:?} unit for ellipsoid", unit.unit_type).into(),
+                ))
+            }
+            None => 1.0,
+        };
+        let a = parse_number(ellps.a)? * factor;
+        let rf = parse_number(ellps.rf)?;
+        // A null inverse flattening defines a sphere
+        let r = if rf == 0.0 {
+            a
+        } else {
+            a * (1.0 - 1.0 / (3.0 * rf))
+        };
+        self.write_value("R", r)
+    }
+
+    // Write the semi-major axis and the inverse flattening,
+    // or the semi-minor axis for a sphere
+    fn write_semi_axes(&mut self, a: &'a str, rf: &'a str, sphere: bool) -> Result<()> {
+        self.write_value_str("a", a)?;
+        if sphere {
+            self.write_value_str("b", a)
+        } else {
+            self.write_value_str("rf", rf)
+        }
+    }
 
-            // TODO check how to get relevant axis units on wkt2
+    fn add_projcs(&mut self, projcs: &Projcs<'a>) -> Result<()> {
+        // Check the projection
+        if let Some((mapping, kind)) = find_method_mapping(&projcs.projection.method) {
+            self.report.method_match = Some(kind);
+            let oriented = oriented_method_mapping(mapping, &projcs.axes);
+            if projcs.axes.is_empty() && oriented.epsg_code() != mapping.epsg_code() {
+                self.report.diagnostics.warn(format!(
+                    "No axes defined for '{}', assuming east/north axes",
+                    projcs.name
+                ));
+            }
+            let mapping = oriented;
+            match mapping.proj_name() {
+                "tmerc" if self.options.prefer_etmerc => {
+                    self.write_param_static("proj", "etmerc")?
+                }
+                name => self.write_param_static("proj", name)?,
+            }
 
-            let axis_unit = projcs.unit.as_ref();
+            // Linear parameters are assumed to be in metre
+            // if no unit is defined
+            let axis_unit = projcs.linear_unit();
             let geod_unit = projcs.geogcs.unit.as_ref();
 
             self.add_parameters(&projcs.projection.parameters, mapping, axis_unit, geod_unit)?;
-            self.add_datum(&projcs.geogcs.datum)?;
-
-            let proj_aux = mapping.proj_aux();
-            if !proj_aux.is_empty() {
-                write!(self.w, " {proj_aux}")?;
+            if self
+                .options
+                .sphere_radius
+                .iter()
+                .any(|p| p == mapping.proj_name())
+            {
+                self.add_sphere_radius(&projcs.geogcs.datum.ellipsoid)?;
+            } else {
+                self.add_datum(&projcs.geogcs.datum)?;
+            }
+            self.add_prime_meridian(&projcs.geogcs)?;
+            // The method may already define the axis orientation
+            if !mapping.proj_aux().contains("+axis=") {
+                self.add_axis(&projcs.axes)?;
             }
-            Ok(())
+
+            self.write_aux(mapping.proj_aux())?;
+            self.add_lon_wrap(projcs.usage.as_ref())
         } else {
             Err(Error::Wkt(
                 format!(
@@ -151,68 +780,276 @@ impl<T: Write> Formatter<T> {
         }
     }
 
+    // Emit `+axis=` for westing or southing axes,
+    // north/east swaps are ignored as in PROJ.
+    fn add_axis(&mut self, axes: &[Axis<'a>]) -> Result<()> {
+        let mut axes: Vec<&Axis> = axes.iter().collect();
+        // Axes without ORDER keep their position
+        axes.sort_by_key(|ax| ax.order.unwrap_or(u8::MAX));
+
+        let mut axis = String::new();
+        for ax in axes.iter().take(2) {
+            axis.push(match ax.direction {
+                AxisDirection::East => 'e',
+                AxisDirection::West => 'w',
+                AxisDirection::North => 'n',
+                AxisDirection::South => 's',
+                _ => return Ok(()),
+            });
+        }
+        if axis.len() == 2 && axis.contains(['w', 's']) {
+            axis.push('u');
+            self.w.param("axis", Some(axis.into()))?;
+        }
+        Ok(())
+    }
+
     fn add_parameters(
         &mut self,
-        params: &[Parameter],
+        params: &[Parameter<'a>],
         mapping: &MethodMapping,
         axis_unit: Option<&Unit>,
         geod_unit: Option<&Unit>,
     ) -> Result<()> {
-        fn write_unit<W: Write>(
-            w: &mut W,
-            name: &str,
-            p: &Parameter,
-            ref_unit: Option<&Unit>,
-        ) -> Result<()> {
-            // See https://docs.ogc.org/is/12-063r5/12-063r5.html#66
-            // for constraint on parameter's unit
-            if let Some(unit) = p.unit.as_ref().or(ref_unit) {
-                if unit.unit_type == UnitType::Linear {
-                    if unit.factor != 1.0 {
-                        return parse_number(p.value).and_then(|value| {
-                            write!(w, " +{}={}", name, value * unit.factor).map_err(Error::from)
-                        });
-                    }
-                } else if !unit.name.eq_ignore_ascii_case("degree") {
-                    return parse_number(p.value).and_then(|value| {
-                        write!(w, " +{}={}", name, (value * unit.factor).to_degrees())
-                            .map_err(Error::from)
-                    });
-                }
-            }
-            write!(w, " +{}={}", name, p.value).map_err(Error::from)
-        }
+        // Mapped parameters already written, as (proj name, value)
+        let mut seen: Vec<(&str, &str)> = vec![];
 
         params.iter().try_for_each(|p| {
-            if let Some(pm) = mapping.find_proj_param(p) {
-                match pm.unit_type {
-                    UnitType::Linear => write_unit(&mut self.w, pm.proj_name, p, axis_unit),
-                    UnitType::Angular => write_unit(&mut self.w, pm.proj_name, p, geod_unit),
-                    _ => write!(self.w, " +{}={}", pm.proj_name, p.value).map_err(Error::from),
-                }
-            } else {
+            let mut mapped = mapping.find_proj_params(p).peekable();
+            let Some(&(pm, kind)) = mapped.peek() else {
                 // Irrelevant proj mapping
-                Ok(())
+                self.report.dropped_parameters.push(p.name.into());
+                self.report.diagnostics.warn(format!(
+                    "Parameter '{}' has no proj mapping and was ignored",
+                    p.name
+                ));
+                return Ok(());
+            };
+            if let Some((_, value)) = seen.iter().find(|(name, _)| *name == pm.proj_name) {
+                if parse_number(value)? != parse_number(p.value)? {
+                    return Err(Error::Wkt(
+                        format!("Conflicting values for parameter '{}'", p.name).into(),
+                    ));
+                }
+                self.report
+                    .diagnostics
+                    .warn(format!("Duplicate parameter '{}' was ignored", p.name));
+                return Ok(());
             }
+            self.report.parameters.push((p.name.into(), kind));
+            mapped
+                // Mapped parameters with no proj name are never exported
+                // i.e latitude of natural origin for Mercator (variant A)
+                // which is always zero.
+                .filter(|(pm, _)| !pm.proj_name.is_empty())
+                .try_for_each(|(pm, _)| {
+                    seen.push((pm.proj_name, p.value));
+                    self.write_mapped_param(pm, p, axis_unit, geod_unit)
+                })
         })?;
 
-        match axis_unit {
-            Some(unit) => {
-                if unit.factor != 1.0 {
-                    write!(self.w, " +to_meter={}", unit.factor)?;
-                } else {
-                    self.write_str(" +units=m")?;
+        for pm in mapping.required_params() {
+            if !seen.iter().any(|(name, _)| *name == pm.proj_name) {
+                let msg = format!(
+                    "Missing required parameter for {}: {}",
+                    mapping.wkt2_name(),
+                    pm.wkt2_name
+                );
+                if self.options.strict_validation {
+                    return Err(Error::Wkt(msg.into()));
                 }
+                self.report.diagnostics.warn(msg);
             }
-            None => {
-                self.write_str(" +units=m")?;
+        }
+
+        self.write_linear_unit(axis_unit)
+    }
+
+    fn write_linear_unit(&mut self, unit: Option<&Unit>) -> Result<()> {
+        let token = linear_axis_token(unit);
+        match token.strip_prefix("+to_meter=") {
+            Some(factor) => self.w.param("to_meter", Some(factor.to_owned().into())),
+            None if self.options.always_output_units => self.write_param_static("units", "m"),
+            None => Ok(()),
+        }
+    }
+
+    fn write_mapped_param(
+        &mut self,
+        pm: &ParamMapping,
+        p: &Parameter<'a>,
+        axis_unit: Option<&Unit>,
+        geod_unit: Option<&Unit>,
+    ) -> Result<()> {
+        match pm.unit_type {
+            UnitType::Linear => self.write_unit(pm.proj_name, p, axis_unit),
+            UnitType::Angular => self.write_unit(pm.proj_name, p, geod_unit),
+            UnitType::Scale => match &p.unit {
+                Some(unit) if matches!(unit.unit_type, UnitType::Linear | UnitType::Angular) => {
+                    self.report.diagnostics.warn(format!(
+                        "Unexpected unit '{}' for scale parameter '{}' was ignored",
+                        unit.name, p.name
+                    ));
+                    self.write_param_str(pm.proj_name, p.value)
+                }
+                Some(unit) if !unit.is_unity() => {
+                    self.write_param(pm.proj_name, parse_number(p.value)? * unit.factor)
+                }
+                _ => self.write_param_str(pm.proj_name, p.value),
+            },
+            _ => self.write_param_str(pm.proj_name, p.value),
+        }
+    }
+
+    fn write_unit(
+        &mut self,
+        name: &'static str,
+        p: &Parameter<'a>,
+        ref_unit: Option<&Unit>,
+    ) -> Result<()> {
+        // See https://docs.ogc.org/is/12-063r5/12-063r5.html#66
+        // for constraint on parameter's unit
+        if let Some(unit) = p.unit.as_ref().or(ref_unit) {
+            if unit.unit_type == UnitType::Linear {
+                if unit.factor != 1.0 {
+                    return self.write_param(name, parse_number(p.value)? * unit.factor);
+                }
+            } else if !unit.is_degree() {
+                return self.write_param(name, (parse_number(p.value)? * unit.factor).to_degrees());
             }
         }
+        self.write_param_str(name, p.value)
+    }
+}
+
+// Ranges of projection parameters as `(proj names, min, max, valid)`.
+// Valid ranges are the physical bounds of the parameter, values
+// out of them fail under strict validation. Longitudes beyond ±360
+// are most likely a missing or wrong unit conversion. The other
+// ranges are plausible values and only raise a warning. Valid
+// ranges of a parameter come first.
+const PARAM_RANGES: [(&[&str], f64, f64, bool); 5] = [
+    (&["lat_0", "lat_1", "lat_2", "lat_ts"], -90.0, 90.0, true),
+    (&["lon_0", "lonc", "lon_1", "lon_2"], -360.0, 360.0, true),
+    (&["lon_0", "lonc", "lon_1", "lon_2"], -180.0, 180.0, false),
+    (&["k", "k_0"], f64::MIN_POSITIVE, f64::INFINITY, true),
+    (&["k", "k_0"], 0.9, 1.1, false),
+];
+
+// Tolerance for rounding errors from unit conversions
+const RANGE_TOLERANCE: f64 = 1e-9;
+
+fn find_param_ranges(
+    name: &str,
+) -> impl Iterator<Item = &'static (&'static [&'static str], f64, f64, bool)> + '_ {
+    PARAM_RANGES
+        .iter()
+        .filter(move |(names, _, _, _)| names.contains(&name))
+}
+
+// Return the single token defining a linear unit,
+// either `+units=m` or `+to_meter=<factor>`
+fn linear_axis_token(unit: Option<&Unit>) -> String {
+    match unit {
+        Some(unit) if unit.factor != 1.0 => format!("+to_meter={}", unit.factor),
+        _ => "+units=m".into(),
+    }
+}
 
+// Return the proj name of a linear unit from
+// its conversion factor to metre
+fn proj_linear_unit(factor: f64) -> Option<&'static str> {
+    const UNITS: [(f64, &str); 3] = [(1.0, "m"), (0.3048, "ft"), (1200.0 / 3937.0, "us-ft")];
+    UNITS
+        .iter()
+        .find(|(f, _)| (f - factor).abs() < 1e-12)
+        .map(|(_, name)| *name)
+}
+
+impl<'a> Formatter<Utf8Writer<'a>> {
+    /// Create a new Formatter appending to a `String`
+    pub fn new_string(buf: &'a mut String) -> Self {
+        Self::new(Utf8Writer(buf))
+    }
+}
+
+/// Proj parameters built from a WKT CRS syntactic tree
+///
+/// Parameters are collected as `(key, value)` pairs without building
+/// the proj string: values emitted verbatim borrow from the tree.
+/// Flags such as `+guam` have an empty value.
+///
+/// The pairs are collected eagerly: the conversion validates the
+/// whole tree and may fail after some parameters were emitted, so
+/// parameters are only returned once it has succeeded. Only the
+/// pair list is allocated, iterating with [`iter`](Self::iter) or
+/// [`into_iter`](IntoIterator::into_iter) is allocation free.
+///
+/// Example:
+///
+/// ```
+/// use proj4wkt::{Builder, ProjParams};
+///
+/// let node = Builder::new()
+///     .parse(concat!(
+///         r#"GEOGCS["WGS 84",DATUM["WGS_1984",SPHEROID["WGS 84",6378137,298.257223563]],"#,
+///         r#"PRIMEM["Greenwich",0],UNIT["degree",0.0174532925199433]]"#,
+///     ))
+///     .unwrap();
+///
+/// let params = ProjParams::try_from(&node).unwrap();
+/// assert_eq!(params.get("proj"), Some("longlat"));
+/// assert_eq!(params.get("a"), Some("6378137"));
+/// ```
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct ProjParams<'a> {
+    params: Vec<(&'static str, Cow<'a, str>)>,
+}
+
+impl<'a> ProjParams<'a> {
+    /// Return an iterator over the `(key, value)` pairs
+    pub fn iter(&self) -> impl Iterator<Item = (&'static str, &str)> {
+        self.params.iter().map(|(k, v)| (*k, v.as_ref()))
+    }
+
+    /// Return the value of parameter `key`
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.iter().find(|(k, _)| *k == key).map(|(_, v)| v)
+    }
+}
+
+impl<'a> Sink<'a> for ProjParams<'a> {
+    fn param(&mut self, key: &'static str, value: Option<Cow<'a, str>>) -> Result<()> {
+        self.params.push((key, value.unwrap_or_default()));
         Ok(())
     }
 }
 
+impl<'a> IntoIterator for ProjParams<'a> {
+    type Item = (&'static str, Cow<'a, str>);
+    type IntoIter = std::vec::IntoIter<Self::Item>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.params.into_iter()
+    }
+}
+
+impl<'a> TryFrom<&Node<'a>> for ProjParams<'a> {
+    type Error = Error;
+
+    fn try_from(node: &Node<'a>) -> Result<Self> {
+        let mut formatter = Formatter::new(Self::default());
+        formatter.emit(node)?;
+        Ok(formatter.w)
+    }
+}
+
+// Drop the floating point noise of a computed value, i.e. a
+// unit conversion, by keeping 15 significant digits
+fn round_computed(v: f64) -> f64 {
+    format!("{v:.14e}").parse().unwrap_or(v)
+}
+
 // ==============================
 //  Tests
 // ==============================
@@ -226,7 +1063,7 @@ mod tests {
         let mut buf = String::new();
         Builder::new()
             .parse(i)
-            .and_then(|node| Formatter::new(unsafe { buf.as_mut_vec() }).format(&node))
+            .and_then(|node| Formatter::new_string(&mut buf).format(&node))
             .and(Ok(buf))
     }
 
@@ -243,4 +1080,1383 @@ mod tests {
             )
         );
     }
+
+    #[test]
+    fn convert_geogcs_wgs84() {
+        setup();
+        let projstr = to_projstring(fixtures::WKT_GEOGCS_WGS84).unwrap();
+        assert_eq!(
+            projstr,
+            "+proj=longlat +a=6378137 +rf=298.257223563 +towgs84=0,0,0,0,0,0,0"
+        );
+    }
+
+    #[test]
+    fn convert_geogcs_paris() {
+        setup();
+        let projstr = to_projstring(fixtures::WKT_GEOGCS_NTF_PARIS).unwrap();
+        assert_eq!(
+            projstr,
+            concat!(
+                "+proj=longlat +a=6378249.2 +rf=293.4660212936269",
+                " +towgs84=0,0,0,0,0,0,0 +pm=paris"
+            )
+        );
+    }
+
+    #[test]
+    fn convert_projcrs_guam() {
+        setup();
+        let projstr = to_projstring(fixtures::WKT_PROJCRS_GUAM).unwrap();
+        assert_eq!(
+            projstr,
+            concat!(
+                "+proj=aeqd +lat_0=13.4724663527778 +lon_0=144.748750705556",
+                " +x_0=50000 +y_0=50000 +units=m +a=6378206.4 +rf=294.978698213898",
+                " +towgs84=0,0,0,0,0,0,0 +guam",
+            )
+        );
+    }
+
+    #[test]
+    fn convert_datum_preset() {
+        setup();
+        let projstr = to_projstring(concat!(
+            r#"GEOGCS["NZGD49",DATUM["New_Zealand_Geodetic_Datum_1949","#,
+            r#"SPHEROID["International 1924",6378388,297]],"#,
+            r#"PRIMEM["Greenwich",0],UNIT["degree",0.0174532925199433]]"#,
+        ))
+        .unwrap();
+        assert_eq!(
+            projstr,
+            concat!(
+                "+proj=longlat +a=6378388 +rf=297",
+                " +towgs84=59.47,-5.04,187.44,0.47,-0.1,1.024,-4.5993",
+            )
+        );
+    }
+
+    #[test]
+    fn format_datum_and_ellipsoid() {
+        setup();
+        let node = Builder::new().parse(fixtures::WKT_PROJCS_NAD83).unwrap();
+        let datum = node.datum().unwrap();
+
+        let mut buf = String::new();
+        Formatter::new_string(&mut buf)
+            .format_ellipsoid(&datum.ellipsoid)
+            .unwrap();
+        assert_eq!(buf, "+a=6378137 +rf=298.257222101");
+
+        let node = Builder::new().parse(fixtures::WKT_PROJCS_OSGB36).unwrap();
+        let mut buf = String::new();
+        Formatter::new_string(&mut buf)
+            .format_datum(node.datum().unwrap())
+            .unwrap();
+        assert_eq!(
+            buf,
+            concat!(
+                "+a=6377563.396 +rf=299.3249646",
+                " +towgs84=446.448,-125.157,542.060,0.1502,0.2470,0.8421,-20.4894",
+            )
+        );
+    }
+
+    #[test]
+    fn convert_projcs_osgb36() {
+        setup();
+        let projstr = to_projstring(fixtures::WKT_PROJCS_OSGB36).unwrap();
+        assert_eq!(
+            projstr,
+            concat!(
+                "+proj=tmerc +lat_0=49 +lon_0=-2 +k=0.9996012717 +x_0=400000 +y_0=-100000",
+                " +units=m +a=6377563.396 +rf=299.3249646",
+                " +towgs84=446.448,-125.157,542.060,0.1502,0.2470,0.8421,-20.4894",
+            )
+        );
+    }
+
+    #[test]
+    fn convert_geodcrs_geocentric() {
+        setup();
+        let projstr = to_projstring(fixtures::WKT_GEODCRS_WGS84_GEOCENTRIC).unwrap();
+        assert_eq!(
+            projstr,
+            "+proj=geocent +a=6378137 +rf=298.257223563 +towgs84=0,0,0,0,0,0,0 +units=m"
+        );
+    }
+
+    #[test]
+    fn convert_with_precision() {
+        setup();
+        let mut buf = String::new();
+        let node = Builder::new().parse(fixtures::WKT_PROJCS_NAD83).unwrap();
+        Formatter::new_string(&mut buf)
+            .options(ConversionOptions::new().precision(6))
+            .format(&node)
+            .unwrap();
+        assert_eq!(
+            buf,
+            concat!(
+                "+proj=lcc +lat_1=42.6833 +lat_2=41.7167 +lat_0=-41 +lon_0=-71.5",
+                " +x_0=200000 +y_0=750000 +units=m +a=6378137 +rf=298.257222101",
+                " +towgs84=0,0,0,0,0,0,0",
+            )
+        );
+    }
+
+    #[test]
+    fn convert_degree_verbatim() {
+        setup();
+        let projstr = to_projstring(concat!(
+            r#"PROJCS["Test",GEOGCS["WGS 84",DATUM["WGS_1984","#,
+            r#"SPHEROID["WGS 84",6378137,298.257223563]],PRIMEM["Greenwich",0],"#,
+            r#"UNIT["Degree (supplier to define representation)",0.0174532925199433]],"#,
+            r#"PROJECTION["Transverse_Mercator"],PARAMETER["latitude_of_origin",0],"#,
+            r#"PARAMETER["central_meridian",-71.5],PARAMETER["scale_factor",0.9996],"#,
+            r#"PARAMETER["false_easting",500000],PARAMETER["false_northing",0],"#,
+            r#"UNIT["metre",1]]"#,
+        ))
+        .unwrap();
+        assert_eq!(
+            projstr,
+            concat!(
+                "+proj=tmerc +lat_0=0 +lon_0=-71.5 +k=0.9996 +x_0=500000 +y_0=0",
+                " +units=m +a=6378137 +rf=298.257223563 +towgs84=0,0,0,0,0,0,0",
+            )
+        );
+    }
+
+    #[test]
+    fn convert_lon_wrap() {
+        setup();
+        let projstr = to_projstring(concat!(
+            r#"GEOGCRS["WGS 84",DATUM["World Geodetic System 1984","#,
+            r#"ELLIPSOID["WGS 84",6378137,298.257223563,LENGTHUNIT["metre",1]]],"#,
+            r#"CS[ellipsoidal,2],AXIS["latitude",north],AXIS["longitude",east],"#,
+            r#"ANGLEUNIT["degree",0.0174532925199433],"#,
+            r#"USAGE[SCOPE["Horizontal component of 3D system."],AREA["World."],"#,
+            r#"BBOX[-90,-180,90,180]]]"#,
+        ))
+        .unwrap();
+        // A world extent does not cross the antimeridian
+        assert_eq!(
+            projstr,
+            "+proj=longlat +a=6378137 +rf=298.257223563 +towgs84=0,0,0,0,0,0,0"
+        );
+
+        let projstr = to_projstring(concat!(
+            r#"PROJCRS["Fiji 1986 / Fiji Map Grid",BASEGEOGCRS["Fiji 1986","#,
+            r#"DATUM["Fiji Geodetic Datum 1986","#,
+            r#"ELLIPSOID["WGS 72",6378135,298.26,LENGTHUNIT["metre",1]]],"#,
+            r#"PRIMEM["Greenwich",0,ANGLEUNIT["degree",0.0174532925199433]]],"#,
+            r#"CONVERSION["Fiji Map Grid",METHOD["Transverse Mercator",ID["EPSG",9807]],"#,
+            r#"PARAMETER["Latitude of natural origin",-17,ID["EPSG",8801]],"#,
+            r#"PARAMETER["Longitude of natural origin",178.75,ID["EPSG",8802]],"#,
+            r#"PARAMETER["Scale factor at natural origin",0.99985,ID["EPSG",8805]],"#,
+            r#"PARAMETER["False easting",2000000,ID["EPSG",8806]],"#,
+            r#"PARAMETER["False northing",4000000,ID["EPSG",8807]]],"#,
+            r#"CS[Cartesian,2],AXIS["easting (X)",east],AXIS["northing (Y)",north],"#,
+            r#"USAGE[SCOPE["Cadastre"],AREA["Fiji"],BBOX[-20.81,176.81,-12.42,-178.15]]]"#,
+        ))
+        .unwrap();
+        assert_eq!(
+            projstr,
+            concat!(
+                "+proj=tmerc +lat_0=-17 +lon_0=178.75 +k=0.99985 +x_0=2000000 +y_0=4000000",
+                " +units=m +a=6378135 +rf=298.26 +towgs84=0,0,0,0,0,0,0 +lon_wrap=180",
+            )
+        );
+    }
+
+    #[test]
+    fn convert_compound_geoid_model() {
+        setup();
+        let projstr = to_projstring(fixtures::WKT_COMPOUNDCRS_NAD83_NAVD88).unwrap();
+        assert_eq!(
+            projstr,
+            concat!(
+                "+proj=longlat +a=6378137 +rf=298.257222101",
+                " +towgs84=0,0,0,0,0,0,0 +geoidgrids=us_noaa_g2012bu0.tif",
+            )
+        );
+
+        // Unknown geoid model
+        let wkt = fixtures::WKT_COMPOUNDCRS_NAD83_NAVD88.replace("GEOID12B", "GEOID99");
+        let (projstr, report) = crate::wkt_to_projstring_with_report(&wkt).unwrap();
+        assert!(!projstr.contains("geoidgrids"), "{projstr}");
+        assert_eq!(
+            report.diagnostics.warnings(),
+            ["No geoid grid known for geoid model 'GEOID99'"]
+        );
+
+        // Grid names are not numeric parameters
+        let mut buf = String::new();
+        let node = Builder::new()
+            .parse(fixtures::WKT_COMPOUNDCRS_NAD83_NAVD88)
+            .unwrap();
+        Formatter::new_string(&mut buf)
+            .options(ConversionOptions::new().precision(6))
+            .format(&node)
+            .unwrap();
+        assert_eq!(
+            buf,
+            concat!(
+                "+proj=longlat +a=6378137 +rf=298.257222101",
+                " +towgs84=0,0,0,0,0,0,0 +geoidgrids=us_noaa_g2012bu0.tif",
+            )
+        );
+    }
+
+    #[test]
+    fn convert_projcs_implicit_metre() {
+        setup();
+        // Ellipsoid in feet, no projected unit: linear parameters
+        // are in metre
+        let projstr = to_projstring(concat!(
+            r#"PROJCS["Test",GEOGCS["NAD27",DATUM["North_American_Datum_1927","#,
+            r#"SPHEROID["Clarke 1866",20925832.164,294.978698213898,"#,
+            r#"UNIT["US survey foot",0.304800609601219]],TOWGS84[-8,160,176]],"#,
+            r#"PRIMEM["Greenwich",0],UNIT["degree",0.0174532925199433]],"#,
+            r#"PROJECTION["Transverse_Mercator"],PARAMETER["latitude_of_origin",0],"#,
+            r#"PARAMETER["central_meridian",-81],PARAMETER["scale_factor",0.9996],"#,
+            r#"PARAMETER["false_easting",500000],PARAMETER["false_northing",0]]"#,
+        ))
+        .unwrap();
+        assert_eq!(
+            projstr,
+            concat!(
+                "+proj=tmerc +lat_0=0 +lon_0=-81 +k=0.9996 +x_0=500000 +y_0=0 +units=m",
+                " +a=6378206.4 +rf=294.978698213898 +towgs84=-8,160,176",
+            )
+        );
+    }
+
+    #[test]
+    fn convert_projcrs_axis_unit() {
+        setup();
+        // WKT2 linear unit defined in AXIS
+        let projstr = to_projstring(concat!(
+            r#"PROJCRS["Test",BASEGEOGCRS["NAD83",DATUM["North American Datum 1983","#,
+            r#"ELLIPSOID["GRS 1980",6378137,298.257222101,LENGTHUNIT["metre",1]]]],"#,
+            r#"CONVERSION["Test",METHOD["Transverse Mercator",ID["EPSG",9807]],"#,
+            r#"PARAMETER["Latitude of natural origin",0,ID["EPSG",8801]],"#,
+            r#"PARAMETER["Longitude of natural origin",-81,ID["EPSG",8802]],"#,
+            r#"PARAMETER["Scale factor at natural origin",0.9996,ID["EPSG",8805]],"#,
+            r#"PARAMETER["False easting",1640416.667,ID["EPSG",8806]],"#,
+            r#"PARAMETER["False northing",0,ID["EPSG",8807]]],CS[Cartesian,2],"#,
+            r#"AXIS["easting (X)",east,LENGTHUNIT["US survey foot",0.304800609601219]],"#,
+            r#"AXIS["northing (Y)",north,LENGTHUNIT["US survey foot",0.304800609601219]]]"#,
+        ))
+        .unwrap();
+        assert_eq!(
+            projstr,
+            concat!(
+                "+proj=tmerc +lat_0=0 +lon_0=-81 +k=0.9996 +x_0=500000.0001016 +y_0=0",
+                " +to_meter=0.304800609601219 +a=6378137 +rf=298.257222101",
+                " +towgs84=0,0,0,0,0,0,0",
+            )
+        );
+    }
+
+    #[test]
+    fn convert_esri_stereographic_pole() {
+        setup();
+        let projstr = to_projstring(concat!(
+            r#"PROJCS["North_Pole_Stereographic",GEOGCS["GCS_WGS_1984","#,
+            r#"DATUM["D_WGS_1984",SPHEROID["WGS_1984",6378137.0,298.257223563]],"#,
+            r#"PRIMEM["Greenwich",0.0],UNIT["Degree",0.0174532925199433]],"#,
+            r#"PROJECTION["Stereographic_North_Pole"],PARAMETER["False_Easting",0.0],"#,
+            r#"PARAMETER["False_Northing",0.0],PARAMETER["Central_Meridian",0.0],"#,
+            r#"PARAMETER["Standard_Parallel_1",71.0],UNIT["Meter",1.0]]"#,
+        ))
+        .unwrap();
+        assert_eq!(
+            projstr,
+            concat!(
+                "+proj=stere +x_0=0.0 +y_0=0.0 +lon_0=0.0 +lat_ts=71.0 +units=m",
+                " +a=6378137.0 +rf=298.257223563 +towgs84=0,0,0,0,0,0,0 +lat_0=90",
+            )
+        );
+
+        let projstr = to_projstring(concat!(
+            r#"PROJCS["South_Pole_Stereographic",GEOGCS["GCS_WGS_1984","#,
+            r#"DATUM["D_WGS_1984",SPHEROID["WGS_1984",6378137.0,298.257223563]],"#,
+            r#"PRIMEM["Greenwich",0.0],UNIT["Degree",0.0174532925199433]],"#,
+            r#"PROJECTION["Stereographic_South_Pole"],PARAMETER["False_Easting",0.0],"#,
+            r#"PARAMETER["False_Northing",0.0],PARAMETER["Central_Meridian",0.0],"#,
+            r#"PARAMETER["Standard_Parallel_1",-71.0],UNIT["Meter",1.0]]"#,
+        ))
+        .unwrap();
+        assert!(projstr.starts_with("+proj=stere "));
+        assert!(projstr.contains(" +lat_ts=-71.0 "));
+        assert!(projstr.ends_with(" +lat_0=-90"));
+    }
+
+    #[test]
+    fn convert_parameter_grad_unit() {
+        setup();
+        let projstr = to_projstring(concat!(
+            r#"PROJCRS["NTF (Paris) / Lambert zone II",BASEGEOGCRS["NTF (Paris)","#,
+            r#"DATUM["Nouvelle Triangulation Francaise (Paris)","#,
+            r#"ELLIPSOID["Clarke 1880 (IGN)",6378249.2,293.466021293627,LENGTHUNIT["metre",1]]],"#,
+            r#"PRIMEM["Paris",2.5969213,ANGLEUNIT["grad",0.0157079632679489]]],"#,
+            r#"CONVERSION["Lambert zone II",METHOD["Lambert Conic Conformal (1SP)",ID["EPSG",9801]],"#,
+            r#"PARAMETER["Latitude of natural origin",52,"#,
+            r#"ANGLEUNIT["grad",0.015707963267948966],ID["EPSG",8801]],"#,
+            r#"PARAMETER["Longitude of natural origin",0,"#,
+            r#"ANGLEUNIT["grad",0.015707963267948966],ID["EPSG",8802]],"#,
+            r#"PARAMETER["Scale factor at natural origin",0.99987742,"#,
+            r#"SCALEUNIT["unity",1],ID["EPSG",8805]],"#,
+            r#"PARAMETER["False easting",600000,LENGTHUNIT["metre",1],ID["EPSG",8806]],"#,
+            r#"PARAMETER["False northing",2200000,LENGTHUNIT["metre",1],ID["EPSG",8807]]],"#,
+            r#"CS[Cartesian,2],AXIS["easting (X)",east,ORDER[1],LENGTHUNIT["metre",1]],"#,
+            r#"AXIS["northing (Y)",north,ORDER[2],LENGTHUNIT["metre",1]],ID["EPSG",27572]]"#,
+        ))
+        .unwrap();
+
+        let param = |name: &str| {
+            projstr
+                .split_whitespace()
+                .find_map(|p| p.strip_prefix(name))
+                .map(|v| parse_number(v).unwrap())
+        };
+
+        assert!(projstr.starts_with("+proj=lcc "));
+        // 52 grads == 46.8 degrees
+        assert!((param("+lat_1=").unwrap() - 46.8).abs() < 1e-12);
+        assert_eq!(param("+lon_0="), Some(0.));
+        assert_eq!(param("+k_0="), Some(0.99987742));
+        assert_eq!(param("+x_0="), Some(600000.));
+        assert_eq!(param("+y_0="), Some(2200000.));
+        // Prime meridian given in grads
+        assert!(projstr.ends_with(" +pm=paris"));
+    }
+
+    #[test]
+    fn convert_ellipsoid_in_feet() {
+        setup();
+        let projstr = crate::wkt_to_projstring(fixtures::WKT_GEOGCS_NAD27_FEET).unwrap();
+
+        let param = |name: &str| {
+            projstr
+                .split_whitespace()
+                .find_map(|p| p.strip_prefix(name))
+                .map(|v| parse_number(v).unwrap())
+        };
+
+        assert!(projstr.starts_with("+proj=longlat "));
+        // Semi-major axis in metre, without conversion noise
+        assert!(projstr.contains(" +a=6378206.4 "), "{projstr}");
+        // Inverse flattening is dimensionless
+        assert_eq!(param("+rf="), Some(294.9786982));
+    }
+
+    #[test]
+    fn convert_geogcs_wgs84_collapsed() {
+        setup();
+        let mut buf = String::new();
+        Builder::new()
+            .parse(fixtures::WKT_GEOGCS_WGS84)
+            .and_then(|node| {
+                Formatter::new_string(&mut buf)
+                    .options(ConversionOptions::new().collapse_wgs84(true))
+                    .format(&node)
+            })
+            .unwrap();
+        assert_eq!(buf, "+proj=longlat +datum=WGS84");
+
+        let mut buf = String::new();
+        Builder::new()
+            .parse(fixtures::WKT_GEOGCS_WGS84)
+            .and_then(|node| {
+                Formatter::new_string(&mut buf)
+                    .options(
+                        ConversionOptions::new()
+                            .collapse_wgs84(true)
+                            .redundant_ellipsoid(true),
+                    )
+                    .format(&node)
+            })
+            .unwrap();
+        assert_eq!(
+            buf,
+            "+proj=longlat +datum=WGS84 +a=6378137 +rf=298.257223563"
+        );
+
+        // Not WGS84
+        let mut buf = String::new();
+        Builder::new()
+            .parse(fixtures::WKT_PROJCS_NAD83)
+            .and_then(|node| {
+                Formatter::new_string(&mut buf)
+                    .options(ConversionOptions::new().collapse_wgs84(true))
+                    .format(&node)
+            })
+            .unwrap();
+        assert!(!buf.contains("+datum=WGS84"));
+    }
+
+    #[test]
+    fn convert_duplicate_parameters() {
+        setup();
+        let wkt = |central_meridian| {
+            format!(
+                concat!(
+                    r#"PROJCS["Test",GEOGCS["WGS 84",DATUM["WGS_1984","#,
+                    r#"SPHEROID["WGS 84",6378137,298.257223563]],PRIMEM["Greenwich",0],"#,
+                    r#"UNIT["degree",0.0174532925199433]],PROJECTION["Transverse_Mercator"],"#,
+                    r#"PARAMETER["latitude_of_origin",0],PARAMETER["central_meridian",3],"#,
+                    r#"PARAMETER["scale_factor",0.9996],PARAMETER["false_easting",500000],"#,
+                    r#"PARAMETER["false_northing",0],PARAMETER["Central_Meridian",{}],"#,
+                    r#"UNIT["metre",1]]"#,
+                ),
+                central_meridian
+            )
+        };
+
+        // Conflicting values
+        assert!(to_projstring(&wkt("9")).is_err());
+
+        // Identical values
+        let (projstr, diagnostics) =
+            crate::wkt_to_projstring_with_diagnostics(&wkt("3.0")).unwrap();
+        assert_eq!(projstr.matches("+lon_0=").count(), 1);
+        assert_eq!(diagnostics.warnings().len(), 1);
+    }
+
+    #[test]
+    fn convert_scale_unit() {
+        setup();
+        let projstr = to_projstring(concat!(
+            r#"PROJCRS["Test",BASEGEOGCRS["WGS 84",DATUM["World Geodetic System 1984","#,
+            r#"ELLIPSOID["WGS 84",6378137,298.257223563,LENGTHUNIT["metre",1]]],"#,
+            r#"PRIMEM["Greenwich",0,ANGLEUNIT["degree",0.0174532925199433]]],"#,
+            r#"CONVERSION["Test",METHOD["Transverse Mercator",ID["EPSG",9807]],"#,
+            r#"PARAMETER["Latitude of natural origin",0,"#,
+            r#"ANGLEUNIT["degree",0.0174532925199433],ID["EPSG",8801]],"#,
+            r#"PARAMETER["Longitude of natural origin",3,"#,
+            r#"ANGLEUNIT["degree",0.0174532925199433],ID["EPSG",8802]],"#,
+            r#"PARAMETER["Scale factor at natural origin",999600,"#,
+            r#"SCALEUNIT["parts per million",1E-06],ID["EPSG",8805]],"#,
+            r#"PARAMETER["False easting",500000,LENGTHUNIT["metre",1],ID["EPSG",8806]],"#,
+            r#"PARAMETER["False northing",0,LENGTHUNIT["metre",1],ID["EPSG",8807]]],"#,
+            r#"CS[Cartesian,2],AXIS["easting (X)",east,ORDER[1],LENGTHUNIT["metre",1]],"#,
+            r#"AXIS["northing (Y)",north,ORDER[2],LENGTHUNIT["metre",1]]]"#,
+        ))
+        .unwrap();
+
+        let k = projstr
+            .split_whitespace()
+            .find_map(|p| p.strip_prefix("+k="))
+            .map(|v| parse_number(v).unwrap())
+            .unwrap();
+        assert!((k - 0.9996).abs() < 1e-12);
+    }
+
+    #[test]
+    fn convert_known_prime_meridians() {
+        setup();
+        let wkt = |primem| {
+            format!(
+                concat!(
+                    r#"GEOGCS["Test",DATUM["Test",SPHEROID["Bessel 1841",6377397.155,299.1528128]],"#,
+                    r#"{},UNIT["degree",0.0174532925199433]]"#,
+                ),
+                primem
+            )
+        };
+
+        let projstr = to_projstring(&wkt(r#"PRIMEM["Paris",2.33722917]"#)).unwrap();
+        assert!(projstr.ends_with(" +pm=paris"));
+
+        let projstr = to_projstring(&wkt(r#"PRIMEM["Ferro",-17.6666666666667]"#)).unwrap();
+        assert!(projstr.ends_with(" +pm=ferro"));
+
+        let projstr = to_projstring(&wkt(r#"PRIMEM["Greenwich",0]"#)).unwrap();
+        assert!(!projstr.contains("+pm="));
+
+        // Unknown prime meridian
+        let projstr = to_projstring(&wkt(r#"PRIMEM["Somewhere",1.5]"#)).unwrap();
+        assert!(projstr.ends_with(" +pm=1.5"));
+    }
+
+    #[test]
+    fn convert_cylindrical_equal_area_variants() {
+        setup();
+        let wkt = |projection| {
+            format!(
+                concat!(
+                    r#"PROJCS["World",GEOGCS["GCS_WGS_1984",DATUM["D_WGS_1984","#,
+                    r#"SPHEROID["WGS_1984",6378137.0,298.257223563]],PRIMEM["Greenwich",0.0],"#,
+                    r#"UNIT["Degree",0.0174532925199433]],PROJECTION["{}"],"#,
+                    r#"PARAMETER["False_Easting",0.0],PARAMETER["False_Northing",0.0],"#,
+                    r#"PARAMETER["Central_Meridian",0.0],UNIT["Meter",1.0]]"#,
+                ),
+                projection
+            )
+        };
+
+        assert_eq!(
+            to_projstring(&wkt("Behrmann")).unwrap(),
+            concat!(
+                "+proj=cea +x_0=0.0 +y_0=0.0 +lon_0=0.0 +units=m +a=6378137.0",
+                " +rf=298.257223563 +towgs84=0,0,0,0,0,0,0 +lat_ts=30",
+            )
+        );
+        assert!(to_projstring(&wkt("Gall_Stereographic"))
+            .unwrap()
+            .starts_with("+proj=gall +x_0=0.0 +y_0=0.0 +lon_0=0.0 "));
+        assert!(to_projstring(&wkt("Gall"))
+            .unwrap()
+            .starts_with("+proj=gall "));
+    }
+
+    #[test]
+    fn convert_compromise_world_projections() {
+        setup();
+        let wkt = |projection| {
+            format!(
+                concat!(
+                    r#"PROJCS["World",GEOGCS["GCS_WGS_1984",DATUM["D_WGS_1984","#,
+                    r#"SPHEROID["WGS_1984",6378137.0,298.257223563]],PRIMEM["Greenwich",0.0],"#,
+                    r#"UNIT["Degree",0.0174532925199433]],PROJECTION["{}"],"#,
+                    r#"PARAMETER["False_Easting",0.0],PARAMETER["False_Northing",0.0],"#,
+                    r#"PARAMETER["Central_Meridian",10.0],UNIT["Meter",1.0]]"#,
+                ),
+                projection
+            )
+        };
+
+        for (projection, proj) in [
+            ("Robinson", "robin"),
+            ("Natural_Earth", "natearth"),
+            ("Natural_Earth_II", "natearth2"),
+        ] {
+            assert_eq!(
+                to_projstring(&wkt(projection)).unwrap(),
+                format!(
+                    concat!(
+                        "+proj={} +x_0=0.0 +y_0=0.0 +lon_0=10.0 +units=m +a=6378137.0",
+                        " +rf=298.257223563 +towgs84=0,0,0,0,0,0,0",
+                    ),
+                    proj
+                )
+            );
+        }
+
+        // WKT2
+        let wkt = concat!(
+            r#"PROJCRS["World_Natural_Earth_II",BASEGEOGCRS["WGS 84","#,
+            r#"DATUM["World Geodetic System 1984","#,
+            r#"ELLIPSOID["WGS 84",6378137,298.257223563,LENGTHUNIT["metre",1]]],"#,
+            r#"PRIMEM["Greenwich",0,ANGLEUNIT["degree",0.0174532925199433]]],"#,
+            r#"CONVERSION["World_Natural_Earth_II",METHOD["Natural Earth II"],"#,
+            r#"PARAMETER["Longitude of natural origin",0,ANGLEUNIT["degree",0.0174532925199433],"#,
+            r#"ID["EPSG",8802]],PARAMETER["False easting",0,LENGTHUNIT["metre",1],"#,
+            r#"ID["EPSG",8806]],PARAMETER["False northing",0,LENGTHUNIT["metre",1],"#,
+            r#"ID["EPSG",8807]]],CS[Cartesian,2],AXIS["(E)",east,ORDER[1],"#,
+            r#"LENGTHUNIT["metre",1]],AXIS["(N)",north,ORDER[2],LENGTHUNIT["metre",1]]]"#,
+        );
+        assert!(to_projstring(wkt)
+            .unwrap()
+            .starts_with("+proj=natearth2 +lon_0=0 +x_0=0 +y_0=0 "));
+    }
+
+    #[test]
+    fn convert_sphere_radius() {
+        setup();
+        use crate::projstr_parser::ProjString;
+
+        let wkt = concat!(
+            r#"PROJCS["World_Robinson",GEOGCS["GCS_WGS_1984",DATUM["D_WGS_1984","#,
+            r#"SPHEROID["WGS_1984",6378137.0,298.257223563]],PRIMEM["Greenwich",0.0],"#,
+            r#"UNIT["Degree",0.0174532925199433]],PROJECTION["Robinson"],"#,
+            r#"PARAMETER["False_Easting",0.0],PARAMETER["False_Northing",0.0],"#,
+            r#"PARAMETER["Central_Meridian",0.0],UNIT["Meter",1.0]]"#,
+        );
+        let node = Builder::new().parse(wkt).unwrap();
+
+        let mut buf = String::new();
+        Formatter::new_string(&mut buf)
+            .options(ConversionOptions::new().sphere_radius(SPHERICAL_PROJECTIONS))
+            .format(&node)
+            .unwrap();
+        assert!(
+            buf.starts_with("+proj=robin +x_0=0.0 +y_0=0.0 +lon_0=0.0 +units=m +R="),
+            "{buf}"
+        );
+        assert!(!buf.contains("+a=") && !buf.contains("+towgs84="));
+
+        let ps = ProjString::parse(&buf).unwrap();
+        let r = ps.get_number("R").unwrap().unwrap();
+        assert!((r - 6371008.7714).abs() < 1e-3, "{r}");
+
+        // Not listed projection
+        let mut buf = String::new();
+        Formatter::new_string(&mut buf)
+            .options(ConversionOptions::new().sphere_radius(&["moll"]))
+            .format(&node)
+            .unwrap();
+        assert!(buf.contains(" +a=6378137.0 +rf=298.257223563"), "{buf}");
+
+        // Projections built at runtime
+        let projections: Vec<String> = "moll,robin".split(',').map(String::from).collect();
+        let mut buf = String::new();
+        Formatter::new_string(&mut buf)
+            .options(ConversionOptions::new().sphere_radius(projections))
+            .format(&node)
+            .unwrap();
+        assert!(buf.contains(" +R="), "{buf}");
+
+        // Disabled by default
+        assert!(!to_projstring(wkt).unwrap().contains("+R="));
+    }
+
+    #[test]
+    fn convert_engineering_crs() {
+        setup();
+        for wkt in [
+            concat!(
+                r#"ENGCRS["A construction site CRS",EDATUM["P1",ANCHOR["Peg in south corner"]],"#,
+                r#"CS[Cartesian,2],AXIS["site east",southWest,ORDER[1]],"#,
+                r#"AXIS["site north",southEast,ORDER[2]],LENGTHUNIT["metre",1.0]]"#,
+            ),
+            concat!(
+                r#"LOCAL_CS["Unknown",LOCAL_DATUM["Unknown",32767],UNIT["metre",1],"#,
+                r#"AXIS["Easting",EAST],AXIS["Northing",NORTH]]"#,
+            ),
+        ] {
+            let node = Builder::new().parse(wkt).unwrap();
+            assert_eq!(node, Node::OTHER("ENGCRS"));
+
+            let mut buf = String::new();
+            let err = Formatter::new_string(&mut buf).format(&node).unwrap_err();
+            assert!(matches!(
+                err,
+                Error::Wkt(msg) if msg == "Engineering CRS is not supported for proj string conversion"
+            ));
+        }
+    }
+
+    #[test]
+    fn convert_mercator_variant_a() {
+        setup();
+        // EPSG:3395
+        let (projstr, report) = crate::wkt_to_projstring_with_report(concat!(
+            r#"PROJCRS["WGS 84 / World Mercator",BASEGEOGCRS["WGS 84","#,
+            r#"DATUM["World Geodetic System 1984","#,
+            r#"ELLIPSOID["WGS 84",6378137,298.257223563,LENGTHUNIT["metre",1]]],"#,
+            r#"PRIMEM["Greenwich",0,ANGLEUNIT["degree",0.0174532925199433]],ID["EPSG",4326]],"#,
+            r#"CONVERSION["World Mercator",METHOD["Mercator (variant A)",ID["EPSG",9804]],"#,
+            r#"PARAMETER["Latitude of natural origin",0,"#,
+            r#"ANGLEUNIT["degree",0.0174532925199433],ID["EPSG",8801]],"#,
+            r#"PARAMETER["Longitude of natural origin",0,"#,
+            r#"ANGLEUNIT["degree",0.0174532925199433],ID["EPSG",8802]],"#,
+            r#"PARAMETER["Scale factor at natural origin",1,SCALEUNIT["unity",1],ID["EPSG",8805]],"#,
+            r#"PARAMETER["False easting",0,LENGTHUNIT["metre",1],ID["EPSG",8806]],"#,
+            r#"PARAMETER["False northing",0,LENGTHUNIT["metre",1],ID["EPSG",8807]]],"#,
+            r#"CS[Cartesian,2],AXIS["(E)",east,ORDER[1],LENGTHUNIT["metre",1]],"#,
+            r#"AXIS["(N)",north,ORDER[2],LENGTHUNIT["metre",1]],ID["EPSG",3395]]"#,
+        ))
+        .unwrap();
+        // As proj, the latitude of origin is not exported
+        assert_eq!(
+            projstr,
+            concat!(
+                "+proj=merc +lon_0=0 +k=1 +x_0=0 +y_0=0 +units=m",
+                " +a=6378137 +rf=298.257223563 +towgs84=0,0,0,0,0,0,0",
+            )
+        );
+        assert!(report.dropped_parameters.is_empty());
+        assert!(report.diagnostics.is_empty());
+    }
+
+    #[test]
+    fn convert_compound_vertical_unit() {
+        setup();
+        let projstr = to_projstring(concat!(
+            r#"COMPOUNDCRS["NAD83 + NAVD88 height (ftUS)","#,
+            r#"GEOGCRS["NAD83",DATUM["North American Datum 1983","#,
+            r#"ELLIPSOID["GRS 1980",6378137,298.257222101,LENGTHUNIT["metre",1]]],"#,
+            r#"PRIMEM["Greenwich",0,ANGLEUNIT["degree",0.0174532925199433]],"#,
+            r#"CS[ellipsoidal,2],AXIS["geodetic latitude (Lat)",north],"#,
+            r#"AXIS["geodetic longitude (Lon)",east],ANGLEUNIT["degree",0.0174532925199433]],"#,
+            r#"VERTCRS["NAVD88 height (ftUS)",VDATUM["North American Vertical Datum 1988"],"#,
+            r#"CS[vertical,1],AXIS["gravity-related height (H)",up,"#,
+            r#"LENGTHUNIT["US survey foot",0.304800609601219]],"#,
+            r#"GEOIDMODEL["GEOID12B"],ID["EPSG",6360]]]"#,
+        ))
+        .unwrap();
+        assert_eq!(
+            projstr,
+            concat!(
+                "+proj=longlat +a=6378137 +rf=298.257222101",
+                " +towgs84=0,0,0,0,0,0,0 +geoidgrids=us_noaa_g2012bu0.tif +vunits=us-ft",
+            )
+        );
+
+        // WKT1 with unknown vertical unit
+        let projstr = to_projstring(concat!(
+            r#"COMPD_CS["Test",GEOGCS["WGS 84",DATUM["WGS_1984","#,
+            r#"SPHEROID["WGS 84",6378137,298.257223563]],PRIMEM["Greenwich",0],"#,
+            r#"UNIT["degree",0.0174532925199433]],VERT_CS["Test height","#,
+            r#"VERT_DATUM["Test",2005],UNIT["fathom",1.8288],AXIS["Up",UP]]]"#,
+        ))
+        .unwrap();
+        assert!(projstr.ends_with(" +vto_meter=1.8288"));
+    }
+
+    #[test]
+    fn convert_out_of_range_parameters() {
+        setup();
+        let wkt = concat!(
+            r#"PROJCS["Test",GEOGCS["WGS 84",DATUM["WGS_1984","#,
+            r#"SPHEROID["WGS 84",6378137,298.257223563]],PRIMEM["Greenwich",0],"#,
+            r#"UNIT["degree",0.0174532925199433]],PROJECTION["Transverse_Mercator"],"#,
+            r#"PARAMETER["latitude_of_origin",95],PARAMETER["central_meridian",200],"#,
+            r#"PARAMETER["scale_factor",-1],PARAMETER["false_easting",0],"#,
+            r#"PARAMETER["false_northing",0],UNIT["metre",1]]"#,
+        );
+        let node = Builder::new().parse(wkt).unwrap();
+
+        let mut buf = String::new();
+        let mut formatter = Formatter::new_string(&mut buf);
+        formatter.format(&node).unwrap();
+        assert_eq!(
+            formatter.report().diagnostics.warnings(),
+            [
+                "Parameter out of range: lat_0=95",
+                "Parameter out of range: lon_0=200",
+                "Parameter out of range: k=-1",
+            ]
+        );
+
+        let mut buf = String::new();
+        let err = Formatter::new_string(&mut buf)
+            .options(ConversionOptions::new().strict_validation(true))
+            .format(&node)
+            .unwrap_err();
+        assert!(matches!(err, Error::Wkt(msg) if msg == "Parameter out of range: lat_0=95"));
+
+        // Implausible but valid values only warn in strict mode
+        let wkt = wkt.replace("95", "0");
+        let wkt_implausible = wkt.replace("-1", "1.2");
+        let node = Builder::new().parse(&wkt_implausible).unwrap();
+        let mut buf = String::new();
+        let mut formatter = Formatter::new_string(&mut buf)
+            .options(ConversionOptions::new().strict_validation(true));
+        formatter.format(&node).unwrap();
+        assert_eq!(
+            formatter.report().diagnostics.warnings(),
+            [
+                "Parameter out of range: lon_0=200",
+                "Parameter out of range: k=1.2",
+            ]
+        );
+
+        let node = Builder::new().parse(&wkt).unwrap();
+        let mut buf = String::new();
+        let err = Formatter::new_string(&mut buf)
+            .options(ConversionOptions::new().strict_validation(true))
+            .format(&node)
+            .unwrap_err();
+        assert!(matches!(err, Error::Wkt(msg) if msg == "Parameter out of range: k=-1"));
+    }
+
+    #[test]
+    fn linear_axis_unit_token() {
+        let unit = |name, factor| Unit {
+            name,
+            factor,
+            unit_type: UnitType::Linear,
+            authority: None,
+        };
+
+        assert_eq!(linear_axis_token(None), "+units=m");
+        assert_eq!(linear_axis_token(Some(&unit("metre", 1.0))), "+units=m");
+        assert_eq!(
+            linear_axis_token(Some(&unit("foot", 0.3048))),
+            "+to_meter=0.3048"
+        );
+        assert_eq!(
+            linear_axis_token(Some(&unit("US survey foot", 0.304800609601219))),
+            "+to_meter=0.304800609601219"
+        );
+        assert_eq!(
+            linear_axis_token(Some(&unit("kilometre", 1000.0))),
+            "+to_meter=1000"
+        );
+
+        let write = |options: ConversionOptions, u: Option<&Unit>| {
+            let mut buf = String::new();
+            Formatter::new_string(&mut buf)
+                .options(options)
+                .write_linear_unit(u)
+                .unwrap();
+            buf
+        };
+        assert_eq!(write(ConversionOptions::new(), None), " +units=m");
+        let options = ConversionOptions::new()
+            .always_output_units(false)
+            .precision(3);
+        assert_eq!(write(options.clone(), Some(&unit("metre", 1.0))), "");
+        // Units are not rounded
+        assert_eq!(
+            write(options, Some(&unit("US survey foot", 0.304800609601219))),
+            " +to_meter=0.304800609601219"
+        );
+    }
+
+    #[test]
+    fn convert_missing_required_parameter() {
+        setup();
+        let wkt = concat!(
+            r#"PROJCS["Test",GEOGCS["WGS 84",DATUM["WGS_1984","#,
+            r#"SPHEROID["WGS 84",6378137,298.257223563]],PRIMEM["Greenwich",0],"#,
+            r#"UNIT["degree",0.0174532925199433]],PROJECTION["Transverse_Mercator"],"#,
+            r#"PARAMETER["latitude_of_origin",0],PARAMETER["central_meridian",9],"#,
+            r#"PARAMETER["scale_factor",0.9996],PARAMETER["false_northing",0],"#,
+            r#"UNIT["metre",1]]"#,
+        );
+        let node = Builder::new().parse(wkt).unwrap();
+
+        let mut buf = String::new();
+        let mut formatter = Formatter::new_string(&mut buf);
+        formatter.format(&node).unwrap();
+        assert_eq!(
+            formatter.report().diagnostics.warnings(),
+            ["Missing required parameter for Transverse Mercator: False easting"]
+        );
+        assert!(!buf.contains("+x_0="));
+
+        let mut buf = String::new();
+        let err = Formatter::new_string(&mut buf)
+            .options(ConversionOptions::new().strict_validation(true))
+            .format(&node)
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            Error::Wkt(msg) if msg == "Missing required parameter for Transverse Mercator: False easting"
+        ));
+
+        let mapping = crate::methods::find_method_by_wkt1_name("Transverse_Mercator").unwrap();
+        assert_eq!(mapping.required_param_count(), 5);
+        // Latitude of natural origin is never exported for Mercator (variant A)
+        let mapping = crate::methods::find_method_by_wkt1_name("Mercator_1SP").unwrap();
+        assert_eq!(mapping.required_param_count(), 4);
+    }
+
+    #[test]
+    fn convert_validate_angular_parameters() {
+        setup();
+        let wkt = |lon_0: &str| {
+            format!(
+                concat!(
+                    r#"PROJCS["Test",GEOGCS["WGS 84",DATUM["WGS_1984","#,
+                    r#"SPHEROID["WGS 84",6378137,298.257223563]],PRIMEM["Greenwich",0],"#,
+                    r#"UNIT["degree",0.0174532925199433]],PROJECTION["Transverse_Mercator"],"#,
+                    r#"PARAMETER["latitude_of_origin",0],PARAMETER["central_meridian",{}],"#,
+                    r#"PARAMETER["scale_factor",1],PARAMETER["false_easting",0],"#,
+                    r#"PARAMETER["false_northing",0],UNIT["metre",1]]"#,
+                ),
+                lon_0
+            )
+        };
+
+        // Central meridian in grads misread as degrees
+        let wkt_7100 = wkt("7100");
+        let node = Builder::new().parse(&wkt_7100).unwrap();
+
+        let mut buf = String::new();
+        let mut formatter = Formatter::new_string(&mut buf);
+        formatter.format(&node).unwrap();
+        assert_eq!(
+            formatter.report().diagnostics.warnings(),
+            ["Parameter out of range: lon_0=7100"]
+        );
+
+        let mut buf = String::new();
+        let err = Formatter::new_string(&mut buf)
+            .options(ConversionOptions::new().strict_validation(true))
+            .format(&node)
+            .unwrap_err();
+        assert!(matches!(err, Error::Wkt(msg) if msg == "Parameter out of range: lon_0=7100"));
+
+        // Implausible but valid longitude
+        let wkt_200 = wkt("200");
+        let node = Builder::new().parse(&wkt_200).unwrap();
+        let mut buf = String::new();
+        let mut formatter = Formatter::new_string(&mut buf)
+            .options(ConversionOptions::new().strict_validation(true));
+        formatter.format(&node).unwrap();
+        assert_eq!(
+            formatter.report().diagnostics.warnings(),
+            ["Parameter out of range: lon_0=200"]
+        );
+
+        // Converted from grads
+        let node = Builder::new()
+            .parse(concat!(
+                r#"GEOGCS["NTF (Paris)",DATUM["Nouvelle_Triangulation_Francaise_Paris","#,
+                r#"SPHEROID["Clarke 1880 (IGN)",6378249.2,293.4660212936269]],"#,
+                r#"PRIMEM["Paris",2.5969213,ANGLEUNIT["grad",0.015707963267949]],"#,
+                r#"UNIT["grad",0.01570796326794897]]"#,
+            ))
+            .unwrap();
+        let mut buf = String::new();
+        Formatter::new_string(&mut buf)
+            .options(ConversionOptions::new().strict_validation(true))
+            .format(&node)
+            .unwrap();
+    }
+
+    #[test]
+    fn convert_axis_order() {
+        setup();
+        let wkt = |axes: &str| {
+            format!(
+                concat!(
+                    r#"PROJCRS["Hartebeesthoek94 / Lo29",BASEGEOGCRS["Hartebeesthoek94","#,
+                    r#"DATUM["Hartebeesthoek94",ELLIPSOID["WGS 84",6378137,298.257223563,"#,
+                    r#"LENGTHUNIT["metre",1]]],PRIMEM["Greenwich",0,"#,
+                    r#"ANGLEUNIT["degree",0.0174532925199433]]],CONVERSION["South African Survey Grid zone 29","#,
+                    r#"METHOD["Transverse Mercator",ID["EPSG",9807]],"#,
+                    r#"PARAMETER["Latitude of natural origin",0,ANGLEUNIT["degree",0.0174532925199433]],"#,
+                    r#"PARAMETER["Longitude of natural origin",29,ANGLEUNIT["degree",0.0174532925199433]],"#,
+                    r#"PARAMETER["Scale factor at natural origin",1,SCALEUNIT["unity",1]],"#,
+                    r#"PARAMETER["False easting",0,LENGTHUNIT["metre",1]],"#,
+                    r#"PARAMETER["False northing",0,LENGTHUNIT["metre",1]]],CS[Cartesian,2],{}]"#,
+                ),
+                axes
+            )
+        };
+
+        let wkt_ordered = wkt(concat!(
+            r#"AXIS["southing (X)",south,ORDER[2],LENGTHUNIT["metre",1]],"#,
+            r#"AXIS["westing (Y)",west,ORDER[1],LENGTHUNIT["metre",1]]"#,
+        ));
+        let node = Builder::new().parse(&wkt_ordered).unwrap();
+        let Node::PROJCRS(cs) = &node else {
+            panic!("Expecting PROJCRS");
+        };
+        assert_eq!(cs.axes[0].order, Some(2));
+        assert_eq!(cs.axes[1].order, Some(1));
+
+        let mut buf = String::new();
+        Formatter::new_string(&mut buf).format(&node).unwrap();
+        assert!(buf.ends_with(" +axis=wsu"), "{buf}");
+
+        // Axis orientation defined by the method
+        let projstr = to_projstring(&wkt_ordered.replace(
+            r#"METHOD["Transverse Mercator",ID["EPSG",9807]]"#,
+            r#"METHOD["Transverse Mercator (South Orientated)",ID["EPSG",9808]]"#,
+        ))
+        .unwrap();
+        assert_eq!(projstr.matches("+axis=").count(), 1, "{projstr}");
+
+        // Positional order
+        let projstr = to_projstring(&wkt(concat!(
+            r#"AXIS["southing (X)",south,LENGTHUNIT["metre",1]],"#,
+            r#"AXIS["westing (Y)",west,LENGTHUNIT["metre",1]]"#,
+        )))
+        .unwrap();
+        assert!(projstr.contains(" +axis=swu"), "{projstr}");
+
+        // North/east swap is ignored
+        let projstr = to_projstring(&wkt(concat!(
+            r#"AXIS["northing (X)",north,ORDER[1],LENGTHUNIT["metre",1]],"#,
+            r#"AXIS["easting (Y)",east,ORDER[2],LENGTHUNIT["metre",1]]"#,
+        )))
+        .unwrap();
+        assert!(!projstr.contains("+axis"), "{projstr}");
+
+        assert!(Builder::new().parse(r#"AXIS["X",east,ORDER[-1]]"#).is_err());
+    }
+
+    #[test]
+    fn convert_compound_vertical_authority() {
+        setup();
+        let wkt = concat!(
+            r#"COMPD_CS["NAD83 + NAVD88 height",GEOGCS["NAD83","#,
+            r#"DATUM["North_American_Datum_1983",SPHEROID["GRS 1980",6378137,298.257222101]],"#,
+            r#"PRIMEM["Greenwich",0],UNIT["degree",0.0174532925199433]],"#,
+            r#"VERT_CS["NAVD88 height",VERT_DATUM["North American Vertical Datum 1988",2005],"#,
+            r#"UNIT["metre",1],AXIS["Gravity-related height",UP],AUTHORITY["EPSG","5703"]]]"#,
+        );
+        let node = Builder::new().parse(wkt).unwrap();
+        let Node::COMPOUNDCRS(crs) = &node else {
+            panic!("Expecting COMPOUNDCRS");
+        };
+        assert_eq!(
+            crs.v_crs.authority,
+            Some(Authority {
+                name: "EPSG",
+                code: "5703"
+            })
+        );
+        assert!(to_projstring(wkt)
+            .unwrap()
+            .ends_with(" +geoidgrids=us_noaa_g2018u0.tif"));
+
+        // NGVD29 height is not defined by a geoid model, VERTCON grids
+        // are vertical datum transformations
+        let wkt = wkt.replace("5703", "5702");
+        let node = Builder::new().parse(&wkt).unwrap();
+        let mut buf = String::new();
+        let mut formatter = Formatter::new_string(&mut buf);
+        formatter.format(&node).unwrap();
+        assert_eq!(
+            formatter.report().diagnostics.warnings(),
+            ["No geoid grid known for vertical crs EPSG:5702"]
+        );
+        drop(formatter);
+        assert!(!buf.contains("geoidgrids"));
+    }
+
+    #[test]
+    fn convert_strict_towgs84() {
+        setup();
+        let wkt = concat!(
+            r#"GEOGCS["Test",DATUM["Test",SPHEROID["GRS 1980",6378137,298.257222101]],"#,
+            r#"PRIMEM["Greenwich",0],UNIT["degree",0.0174532925199433]]"#,
+        );
+        let convert = |node: &Node, strict: bool| {
+            let mut buf = String::new();
+            let mut formatter = Formatter::new_string(&mut buf)
+                .options(ConversionOptions::new().strict_towgs84(strict));
+            formatter.format(node).unwrap();
+            let warnings = formatter.report().diagnostics.warnings().len();
+            (buf, warnings)
+        };
+
+        let Node::GEOGCRS(cs) = Builder::new().parse(wkt).unwrap() else {
+            panic!("Expecting GEOGCRS");
+        };
+        assert!(!cs.datum.has_explicit_towgs84);
+
+        let expected = "+proj=longlat +a=6378137 +rf=298.257222101 +towgs84=0,0,0,0,0,0,0";
+        let node = Node::GEOGCRS(cs);
+        assert_eq!(convert(&node, false), (expected.into(), 0));
+        // Unknown shift
+        assert_eq!(convert(&node, true), (expected.into(), 1));
+
+        // Explicit null shift
+        let wkt = wkt.replace("298.257222101]", "298.257222101],TOWGS84[0,0,0,0,0,0,0]");
+        let Node::GEOGCRS(cs) = Builder::new().parse(&wkt).unwrap() else {
+            panic!("Expecting GEOGCRS");
+        };
+        assert!(cs.datum.has_explicit_towgs84);
+        assert_eq!(convert(&Node::GEOGCRS(cs), true), (expected.into(), 0));
+    }
+
+    #[test]
+    fn convert_without_null_towgs84() {
+        setup();
+        let wkt = concat!(
+            r#"GEOGCS["NAD83(2011)",DATUM["NAD83_National_Spatial_Reference_System_2011","#,
+            r#"SPHEROID["GRS 1980",6378137,298.257222101]],"#,
+            r#"PRIMEM["Greenwich",0],UNIT["degree",0.0174532925199433]]"#,
+        );
+        let convert = |wkt: &str, assume: bool| {
+            crate::wkt_to_projstring_with_options(
+                wkt,
+                ConversionOptions::new().assume_null_towgs84(assume),
+            )
+            .unwrap()
+        };
+
+        assert_eq!(
+            convert(wkt, true),
+            "+proj=longlat +a=6378137 +rf=298.257222101 +towgs84=0,0,0,0,0,0,0"
+        );
+        assert_eq!(
+            convert(wkt, false),
+            "+proj=longlat +a=6378137 +rf=298.257222101"
+        );
+
+        // Explicit null shift
+        let wkt = wkt.replace("298.257222101]", "298.257222101],TOWGS84[0,0,0,0,0,0,0]");
+        assert_eq!(
+            convert(&wkt, false),
+            "+proj=longlat +a=6378137 +rf=298.257222101 +towgs84=0,0,0,0,0,0,0"
+        );
+
+        // Known shift of a datum preset
+        assert_eq!(
+            convert(fixtures::WKT_PROJCS_OSGB36, false),
+            convert(fixtures::WKT_PROJCS_OSGB36, true)
+        );
+    }
+
+    #[test]
+    fn convert_with_options() {
+        setup();
+        let convert = |wkt: &str, options: ConversionOptions| {
+            let node = Builder::new().parse(wkt).unwrap();
+            let mut buf = String::new();
+            Formatter::new_string(&mut buf)
+                .options(options)
+                .format(&node)
+                .unwrap();
+            buf
+        };
+
+        let wkt = concat!(
+            r#"PROJCS["OSGB 1936 / British National Grid",GEOGCS["OSGB 1936","#,
+            r#"DATUM["OSGB_1936",SPHEROID["Airy 1830",6377563.396,299.3249646]],"#,
+            r#"PRIMEM["Greenwich",0],UNIT["degree",0.0174532925199433]],"#,
+            r#"PROJECTION["Transverse_Mercator"],"#,
+            r#"PARAMETER["latitude_of_origin",49],PARAMETER["central_meridian",-2],"#,
+            r#"PARAMETER["scale_factor",0.9996012717],PARAMETER["false_easting",400000],"#,
+            r#"PARAMETER["false_northing",-100000],UNIT["metre",1]]"#,
+        );
+
+        assert_eq!(ConversionOptions::new(), ConversionOptions::default());
+        assert_eq!(
+            convert(wkt, ConversionOptions::default()),
+            to_projstring(wkt).unwrap()
+        );
+        assert_eq!(
+            convert(
+                wkt,
+                ConversionOptions::new()
+                    .suppress_towgs84(true)
+                    .prefer_etmerc(true)
+                    .always_output_units(false)
+            ),
+            concat!(
+                "+proj=etmerc +lat_0=49 +lon_0=-2 +k=0.9996012717 +x_0=400000 +y_0=-100000",
+                " +a=6377563.396 +rf=299.3249646",
+            )
+        );
+        assert_eq!(
+            convert(wkt, ConversionOptions::new().use_datum_names(true)),
+            concat!(
+                "+proj=tmerc +lat_0=49 +lon_0=-2 +k=0.9996012717 +x_0=400000 +y_0=-100000",
+                " +units=m +datum=OSGB36",
+            )
+        );
+
+        // Explicit shift parameters take precedence over the datum name
+        let wkt = concat!(
+            r#"GEOGCS["OSGB 1936",DATUM["OSGB_1936",SPHEROID["Airy 1830",6377563.396,299.3249646],"#,
+            r#"TOWGS84[375,-111,431,0,0,0,0]],PRIMEM["Greenwich",0],"#,
+            r#"UNIT["degree",0.0174532925199433]]"#,
+        );
+        assert_eq!(
+            convert(
+                wkt,
+                ConversionOptions::new()
+                    .use_datum_names(true)
+                    .use_ellipsoid_names(true)
+            ),
+            "+proj=longlat +ellps=airy +towgs84=375,-111,431,0,0,0,0"
+        );
+
+        // Non metre units are always emitted
+        let projstr = convert(
+            concat!(
+                r#"GEOCCS["WGS 84",DATUM["WGS_1984",SPHEROID["WGS 84",6378137,298.257223563]],"#,
+                r#"PRIMEM["Greenwich",0],UNIT["kilometre",1000]]"#,
+            ),
+            ConversionOptions::new().always_output_units(false),
+        );
+        assert!(projstr.ends_with(" +to_meter=1000"), "{projstr}");
+    }
+
+    #[test]
+    fn convert_base_geographic() {
+        setup();
+        let node = Builder::new()
+            .parse(concat!(
+                r#"PROJCS["NTF (Paris) / Lambert zone II",GEOGCS["NTF (Paris)","#,
+                r#"DATUM["Nouvelle_Triangulation_Francaise_Paris","#,
+                r#"SPHEROID["Clarke 1880 (IGN)",6378249.2,293.4660212936269],"#,
+                r#"TOWGS84[-168,-60,320,0,0,0,0]],PRIMEM["Paris",2.33722917],"#,
+                r#"UNIT["grad",0.01570796326794897]],PROJECTION["Lambert_Conformal_Conic_1SP"],"#,
+                r#"PARAMETER["latitude_of_origin",52],PARAMETER["central_meridian",0],"#,
+                r#"PARAMETER["scale_factor",0.99987742],PARAMETER["false_easting",600000],"#,
+                r#"PARAMETER["false_northing",2200000],UNIT["metre",1]]"#,
+            ))
+            .unwrap();
+        assert_eq!(
+            node.base_geographic().map(|cs| cs.name),
+            Some("NTF (Paris)")
+        );
+
+        let mut buf = String::new();
+        Formatter::new_string(&mut buf)
+            .format_base_geographic(&node)
+            .unwrap();
+        assert_eq!(
+            buf,
+            "+proj=longlat +a=6378249.2 +rf=293.4660212936269 +towgs84=-168,-60,320,0,0,0,0 +pm=paris",
+        );
+
+        // Not a projected crs
+        let node = Builder::new()
+            .parse(concat!(
+                r#"GEOGCS["WGS 84",DATUM["WGS_1984",SPHEROID["WGS 84",6378137,298.257223563]],"#,
+                r#"PRIMEM["Greenwich",0],UNIT["degree",0.0174532925199433]]"#,
+            ))
+            .unwrap();
+        assert!(node.base_geographic().is_none());
+        let mut buf = String::new();
+        assert!(Formatter::new_string(&mut buf)
+            .format_base_geographic(&node)
+            .is_err());
+    }
+
+    #[test]
+    fn convert_prefer_init() {
+        setup();
+        let format = |wkt: &str| {
+            let node = Builder::new().parse(wkt).unwrap();
+            let mut buf = String::new();
+            Formatter::new_string(&mut buf)
+                .options(ConversionOptions::new().prefer_init(true))
+                .format(&node)
+                .unwrap();
+            buf
+        };
+
+        let wkt = concat!(
+            r#"GEOGCS["WGS 84",DATUM["WGS_1984",SPHEROID["WGS 84",6378137,298.257223563]],"#,
+            r#"PRIMEM["Greenwich",0],UNIT["degree",0.0174532925199433],AUTHORITY["EPSG","4326"]]"#,
+        );
+        assert_eq!(format(wkt), "+init=EPSG:4326");
+
+        // WKT2 identifier
+        let wkt = concat!(
+            r#"GEOGCRS["WGS 84",DATUM["World Geodetic System 1984","#,
+            r#"ELLIPSOID["WGS 84",6378137,298.257223563]],PRIMEM["Greenwich",0],"#,
+            r#"CS[ellipsoidal,2],AXIS["latitude",north],AXIS["longitude",east],"#,
+            r#"ANGLEUNIT["degree",0.0174532925199433],ID["EPSG",4326]]"#,
+        );
+        assert_eq!(format(wkt), "+init=EPSG:4326");
+
+        // Non EPSG authority
+        let wkt = concat!(
+            r#"GEOGCS["WGS 84",DATUM["WGS_1984",SPHEROID["WGS 84",6378137,298.257223563]],"#,
+            r#"PRIMEM["Greenwich",0],UNIT["degree",0.0174532925199433],AUTHORITY["IGNF","WGS84G"]]"#,
+        );
+        assert_eq!(
+            format(wkt),
+            "+proj=longlat +a=6378137 +rf=298.257223563 +towgs84=0,0,0,0,0,0,0"
+        );
+    }
+
+    #[test]
+    fn convert_named_ellipsoid() {
+        setup();
+        let node = Builder::new()
+            .parse(concat!(
+                r#"GEOGCS["NAD83",DATUM["North_American_Datum_1983","#,
+                r#"SPHEROID["GRS 1980",6378137,298.2572221008827]],"#,
+                r#"PRIMEM["Greenwich",0],UNIT["degree",0.0174532925199433]]"#,
+            ))
+            .unwrap();
+
+        let mut buf = String::new();
+        Formatter::new_string(&mut buf)
+            .options(ConversionOptions::new().use_ellipsoid_names(true))
+            .format(&node)
+            .unwrap();
+        assert_eq!(buf, "+proj=longlat +ellps=GRS80 +towgs84=0,0,0,0,0,0,0");
+
+        // Unknown ellipsoid
+        let node = Builder::new()
+            .parse(concat!(
+                r#"GEOGCS["Test",DATUM["Test","#,
+                r#"SPHEROID["Test",6378137,298.2572]],"#,
+                r#"PRIMEM["Greenwich",0],UNIT["degree",0.0174532925199433]]"#,
+            ))
+            .unwrap();
+
+        let mut buf = String::new();
+        Formatter::new_string(&mut buf)
+            .options(ConversionOptions::new().use_ellipsoid_names(true))
+            .format(&node)
+            .unwrap();
+        assert!(buf.starts_with("+proj=longlat +a=6378137 +rf=298.2572"));
+
+        // Ellipsoid identified by its EPSG code
+        let wkt = concat!(
+            r#"GEOGCS["NAD27",DATUM["North_American_Datum_1927","#,
+            r#"SPHEROID["Clarke 1866",6378206.4,294.9786982138982,AUTHORITY["EPSG","7008"]]],"#,
+            r#"PRIMEM["Greenwich",0],UNIT["degree",0.0174532925199433]]"#,
+        );
+        let node = Builder::new().parse(wkt).unwrap();
+        let mut buf = String::new();
+        Formatter::new_string(&mut buf)
+            .options(ConversionOptions::new().use_ellipsoid_names(true))
+            .format(&node)
+            .unwrap();
+        assert!(buf.starts_with("+proj=longlat +ellps=clrk66"), "{buf}");
+
+        // Not emitted unless enabled
+        assert!(to_projstring(wkt)
+            .unwrap()
+            .starts_with("+proj=longlat +a=6378206.4 +rf="));
+
+        // Code is ignored if the parameters do not match
+        let wkt = wkt.replace("294.9786982138982", "294.97869");
+        let node = Builder::new().parse(&wkt).unwrap();
+        let mut buf = String::new();
+        Formatter::new_string(&mut buf)
+            .options(ConversionOptions::new().use_ellipsoid_names(true))
+            .format(&node)
+            .unwrap();
+        assert!(
+            buf.starts_with("+proj=longlat +a=6378206.4 +rf=294.97869"),
+            "{buf}"
+        );
+
+        // Parameters take precedence over a mismatched code
+        let wkt = concat!(
+            r#"GEOGCS["Test",DATUM["Test","#,
+            r#"SPHEROID["Test",6378137,298.257223563,AUTHORITY["EPSG","7008"]]],"#,
+            r#"PRIMEM["Greenwich",0],UNIT["degree",0.0174532925199433]]"#,
+        );
+        let node = Builder::new().parse(wkt).unwrap();
+        let mut buf = String::new();
+        Formatter::new_string(&mut buf)
+            .options(ConversionOptions::new().use_ellipsoid_names(true))
+            .format(&node)
+            .unwrap();
+        assert!(buf.starts_with("+proj=longlat +ellps=WGS84"), "{buf}");
+    }
+
+    #[test]
+    fn convert_datum_nadgrids() {
+        setup();
+        let wkt = concat!(
+            r#"GEOGCS["NAD27",DATUM["North_American_Datum_1927","#,
+            r#"SPHEROID["Clarke 1866",6378206.4,294.978698213898],"#,
+            r#"TOWGS84[0,0,0,0,0,0,0],NADGRIDS["ca_nrc_ntv2_0.tif"]],"#,
+            r#"PRIMEM["Greenwich",0],UNIT["degree",0.0174532925199433]]"#,
+        );
+        assert_eq!(
+            to_projstring(wkt).unwrap(),
+            "+proj=longlat +a=6378206.4 +rf=294.978698213898 +nadgrids=ca_nrc_ntv2_0.tif",
+        );
+
+        let projstr = to_projstring(wkt).unwrap();
+        let wkt = crate::projstring_to_wkt(&projstr).unwrap();
+        assert!(wkt.contains(r#"NADGRIDS["ca_nrc_ntv2_0.tif"]"#));
+        assert_eq!(to_projstring(&wkt).unwrap(), projstr);
+
+        // Explicit shift takes precedence over the datum preset
+        let wkt = concat!(
+            r#"GEOGCS["NAD27",DATUM["North_American_Datum_1927","#,
+            r#"SPHEROID["Clarke 1866",6378206.4,294.978698213898],TOWGS84[-8,160,176]],"#,
+            r#"PRIMEM["Greenwich",0],UNIT["degree",0.0174532925199433]]"#,
+        );
+        assert_eq!(
+            to_projstring(wkt).unwrap(),
+            "+proj=longlat +a=6378206.4 +rf=294.978698213898 +towgs84=-8,160,176",
+        );
+        assert!(to_projstring(&wkt.replace("TOWGS84[-8,160,176]", "TOWGS84[]")).is_err());
+    }
 }
@@ -0,0 +1,82 @@
+//!
+//! Parse proj string
+//!
+//! Tokenize a proj string into `+key=value` pairs
+//!
+use crate::builder::parse_number;
+use crate::errors::{Error, Result};
+
+/// A tokenized proj string
+#[derive(Debug, PartialEq)]
+pub struct ProjString<'a> {
+    params: Vec<(&'a str, Option<&'a str>)>,
+}
+
+impl<'a> ProjString<'a> {
+    /// Tokenize a proj string
+    ///
+    /// Each token must be of the form `+key` or `+key=value`.
+    pub fn parse(s: &'a str) -> Result<Self> {
+        s.split_whitespace()
+            .map(|token| {
+                let t = token.strip_prefix('+').unwrap_or(token);
+                let (key, value) = match t.split_once('=') {
+                    Some((key, value)) => (key, Some(value)),
+                    None => (t, None),
+                };
+                if key.is_empty() || value.map_or(false, str::is_empty) {
+                    Err(Error::Wkt(
+                        format!("Invalid proj string token '{token}'").into(),
+                    ))
+                } else {
+                    Ok((key, value))
+                }
+            })
+            .collect::<Result<Vec<_>>>()
+            .map(|params| Self { params })
+    }
+
+    /// Return the value of parameter `key`
+    pub fn get(&self, key: &str) -> Option<&'a str> {
+        self.params
+            .iter()
+            .find(|(k, _)| *k == key)
+            .and_then(|(_, v)| *v)
+    }
+
+    /// Return the numeric value of parameter `key`
+    pub fn get_number(&self, key: &str) -> Result<Option<f64>> {
+        self.get(key).map(parse_number).transpose()
+    }
+
+    /// Return true if the parameter `key` is defined
+    pub fn has(&self, key: &str) -> bool {
+        self.params.iter().any(|(k, _)| *k == key)
+    }
+}
+
+// ==============================
+//  Tests
+// ==============================
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_projstring() {
+        let ps = ProjString::parse("+proj=tmerc +lat_0=49 +k=0.9996  +no_defs").unwrap();
+        assert_eq!(ps.get("proj"), Some("tmerc"));
+        assert_eq!(ps.get_number("lat_0").unwrap(), Some(49.0));
+        assert_eq!(ps.get("k"), Some("0.9996"));
+        assert!(ps.has("no_defs"));
+        assert_eq!(ps.get("no_defs"), None);
+        assert!(!ps.has("lon_0"));
+
+        assert!(ProjString::parse("+proj=").is_err());
+        assert!(ProjString::parse("+=tmerc").is_err());
+        assert!(ProjString::parse("+proj=tmerc +lat_0=foo")
+            .unwrap()
+            .get_number("lat_0")
+            .is_err());
+    }
+}
@@ -0,0 +1,71 @@
+//!
+//! Conversion report
+//!
+//! Report how the projection method and parameters
+//! were resolved during conversion.
+//!
+
+/// How a WKT element was matched against the mapping tables
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MatchKind {
+    /// Matched by EPSG code (high confidence)
+    Epsg,
+    /// Matched by WKT1 or WKT2 name (lower confidence)
+    Name,
+}
+
+/// Conversion diagnostics
+///
+/// Collect warnings emitted during conversion
+#[derive(Debug, Default, PartialEq)]
+pub struct Diagnostics {
+    warnings: Vec<String>,
+}
+
+impl Diagnostics {
+    /// Add a warning
+    pub fn warn(&mut self, msg: impl Into<String>) {
+        let msg = msg.into();
+        crate::log::warn!("{msg}");
+        self.warnings.push(msg);
+    }
+
+    // Append the warnings of `other`, which are already logged
+    pub(crate) fn append(&mut self, other: Diagnostics) {
+        self.warnings.extend(other.warnings);
+    }
+
+    /// Return the collected warnings
+    pub fn warnings(&self) -> &[String] {
+        &self.warnings
+    }
+
+    /// Return true if no warning has been emitted
+    pub fn is_empty(&self) -> bool {
+        self.warnings.is_empty()
+    }
+}
+
+/// Conversion report
+#[derive(Debug, Default, PartialEq)]
+pub struct Report {
+    /// How the projection method was matched, `None`
+    /// for geographic crs.
+    pub method_match: Option<MatchKind>,
+    /// Mapped parameters with the way they were matched
+    pub parameters: Vec<(String, MatchKind)>,
+    /// Parameters with no proj mapping that were dropped
+    pub dropped_parameters: Vec<String>,
+    /// Conversion warnings
+    pub diagnostics: Diagnostics,
+}
+
+impl Report {
+    /// Return true if the method and all the parameters were
+    /// matched by EPSG code and no parameter was dropped.
+    pub fn is_high_confidence(&self) -> bool {
+        self.method_match != Some(MatchKind::Name)
+            && self.parameters.iter().all(|(_, m)| *m == MatchKind::Epsg)
+            && self.dropped_parameters.is_empty()
+    }
+}
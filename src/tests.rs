@@ -3,8 +3,10 @@
 //!
 use crate::builder::{Builder, Node};
 use crate::model::*;
+use crate::parser::{Attribute, Processor};
+use crate::report::{MatchKind, Report};
 
-use env_logger;
+use std::borrow::Cow;
 use std::sync::Once;
 
 static INIT: Once = Once::new();
@@ -16,6 +18,32 @@ pub fn setup() {
     });
 }
 
+/// Check that a proj string is syntactically valid
+///
+/// A proj string is a sequence of `+key` or `+key=value` tokens
+pub fn is_valid_projstring(s: &str) -> bool {
+    !s.is_empty()
+        && s.split(' ').all(|token| {
+            token.strip_prefix('+').map_or(false, |t| {
+                let (key, value) = t.split_once('=').unwrap_or((t, "_"));
+                !key.is_empty()
+                    && !value.is_empty()
+                    && key.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+            })
+        })
+}
+
+/// Convert `wkt` to a proj string, check the result against `expected_proj`
+/// and check that the proj string is syntactically valid.
+pub fn assert_round_trip(wkt: &str, expected_proj: &str) {
+    let projstr = crate::wkt_to_projstring(wkt).unwrap();
+    assert_eq!(projstr, expected_proj);
+    assert!(
+        is_valid_projstring(&projstr),
+        "Invalid proj string: {projstr}"
+    );
+}
+
 pub mod fixtures {
     pub const WKT_PROJCS_NAD83: &str = concat!(
         r#"PROJCS["NAD83 / Massachusetts Mainland",GEOGCS["NAD83","#,
@@ -30,6 +58,317 @@ pub mod fixtures {
         r#"PARAMETER["false_easting",200000],PARAMETER["false_northing",750000],"#,
         r#"AUTHORITY["EPSG","26986"],AXIS["X",EAST],AXIS["Y",NORTH]]"#,
     );
+
+    pub const WKT_GEOGCS_WGS84: &str = concat!(
+        r#"GEOGCS["WGS 84",DATUM["WGS_1984",SPHEROID["WGS 84",6378137,298.257223563,"#,
+        r#"AUTHORITY["EPSG","7030"]],AUTHORITY["EPSG","6326"]],PRIMEM["Greenwich",0,"#,
+        r#"AUTHORITY["EPSG","8901"]],UNIT["degree",0.0174532925199433,"#,
+        r#"AUTHORITY["EPSG","9122"]],AUTHORITY["EPSG","4326"]]"#,
+    );
+
+    pub const WKT_GEOGCS_NTF_PARIS: &str = concat!(
+        r#"GEOGCS["NTF (Paris)",DATUM["Nouvelle_Triangulation_Francaise_Paris","#,
+        r#"SPHEROID["Clarke 1880 (IGN)",6378249.2,293.4660212936269,"#,
+        r#"AUTHORITY["EPSG","7011"]],AUTHORITY["EPSG","6807"]],"#,
+        r#"PRIMEM["Paris",2.33722917,AUTHORITY["EPSG","8903"]],"#,
+        r#"UNIT["degree",0.0174532925199433,AUTHORITY["EPSG","9122"]],"#,
+        r#"AUTHORITY["EPSG","4807"]]"#,
+    );
+
+    pub const WKT_PROJCS_OSGB36: &str = concat!(
+        r#"PROJCS["OSGB 1936 / British National Grid",GEOGCS["OSGB 1936","#,
+        r#"DATUM["OSGB_1936",SPHEROID["Airy 1830",6377563.396,299.3249646,"#,
+        r#"AUTHORITY["EPSG","7001"]],AUTHORITY["EPSG","6277"]],"#,
+        r#"PRIMEM["Greenwich",0,AUTHORITY["EPSG","8901"]],"#,
+        r#"UNIT["degree",0.0174532925199433,AUTHORITY["EPSG","9122"]],"#,
+        r#"AUTHORITY["EPSG","4277"]],PROJECTION["Transverse_Mercator"],"#,
+        r#"PARAMETER["latitude_of_origin",49],PARAMETER["central_meridian",-2],"#,
+        r#"PARAMETER["scale_factor",0.9996012717],PARAMETER["false_easting",400000],"#,
+        r#"PARAMETER["false_northing",-100000],UNIT["metre",1,AUTHORITY["EPSG","9001"]],"#,
+        r#"AXIS["Easting",EAST],AXIS["Northing",NORTH],AUTHORITY["EPSG","27700"]]"#,
+    );
+
+    pub const WKT_PROJCS_OSGB36_PROJECTION_AUTHORITY: &str = concat!(
+        r#"PROJCS["OSGB 1936 / British National Grid",GEOGCS["OSGB 1936","#,
+        r#"DATUM["OSGB_1936",SPHEROID["Airy 1830",6377563.396,299.3249646,"#,
+        r#"AUTHORITY["EPSG","7001"]],AUTHORITY["EPSG","6277"]],"#,
+        r#"PRIMEM["Greenwich",0,AUTHORITY["EPSG","8901"]],"#,
+        r#"UNIT["degree",0.0174532925199433,AUTHORITY["EPSG","9122"]],"#,
+        r#"AUTHORITY["EPSG","4277"]],PROJECTION["Transverse_Mercator",AUTHORITY["EPSG","9807"]],"#,
+        r#"PARAMETER["latitude_of_origin",49],PARAMETER["central_meridian",-2],"#,
+        r#"PARAMETER["scale_factor",0.9996012717],PARAMETER["false_easting",400000],"#,
+        r#"PARAMETER["false_northing",-100000],UNIT["metre",1,AUTHORITY["EPSG","9001"]],"#,
+        r#"AXIS["Easting",EAST],AXIS["Northing",NORTH],AUTHORITY["EPSG","27700"]]"#,
+    );
+
+    pub const WKT_PROJCRS_NAD83_MASSACHUSETTS_FTUS: &str = concat!(
+        r#"PROJCRS["NAD83 / Massachusetts Mainland (ftUS)",BASEGEOGCRS["NAD83","#,
+        r#"DATUM["North American Datum 1983",ELLIPSOID["GRS 1980",6378137,298.257222101,"#,
+        r#"LENGTHUNIT["metre",1]]],PRIMEM["Greenwich",0,ANGLEUNIT["degree",0.0174532925199433]],"#,
+        r#"ID["EPSG",4269]],CONVERSION["SPCS83 Massachusetts Mainland zone (US survey foot)","#,
+        r#"METHOD["Lambert Conic Conformal (2SP)",ID["EPSG",9802]],"#,
+        r#"PARAMETER["Latitude of false origin",41,ANGLEUNIT["degree",0.0174532925199433],"#,
+        r#"ID["EPSG",8821]],"#,
+        r#"PARAMETER["Longitude of false origin",-71.5,ANGLEUNIT["degree",0.0174532925199433],"#,
+        r#"ID["EPSG",8822]],"#,
+        r#"PARAMETER["Latitude of 1st standard parallel",42.6833333333333,"#,
+        r#"ANGLEUNIT["degree",0.0174532925199433],ID["EPSG",8823]],"#,
+        r#"PARAMETER["Latitude of 2nd standard parallel",41.7166666666667,"#,
+        r#"ANGLEUNIT["degree",0.0174532925199433],ID["EPSG",8824]],"#,
+        r#"PARAMETER["Easting at false origin",656166.667,LENGTHUNIT["US survey foot",0.304800609601219],"#,
+        r#"ID["EPSG",8826]],"#,
+        r#"PARAMETER["Northing at false origin",2460625,LENGTHUNIT["US survey foot",0.304800609601219],"#,
+        r#"ID["EPSG",8827]]],CS[Cartesian,2],"#,
+        r#"AXIS["easting (X)",east,ORDER[1],LENGTHUNIT["US survey foot",0.304800609601219]],"#,
+        r#"AXIS["northing (Y)",north,ORDER[2],LENGTHUNIT["US survey foot",0.304800609601219]],"#,
+        r#"ID["EPSG",2249]]"#,
+    );
+
+    pub const WKT_PROJCS_AMERSFOORT: &str = concat!(
+        r#"PROJCS["Amersfoort / RD New",GEOGCS["Amersfoort",DATUM["Amersfoort","#,
+        r#"SPHEROID["Bessel 1841",6377397.155,299.1528128,AUTHORITY["EPSG","7004"]],"#,
+        r#"TOWGS84[565.2369,50.0087,465.658,-0.406857,0.350733,-1.87035,4.0812],"#,
+        r#"AUTHORITY["EPSG","6289"]],PRIMEM["Greenwich",0,AUTHORITY["EPSG","8901"]],"#,
+        r#"UNIT["degree",0.0174532925199433,AUTHORITY["EPSG","9122"]],"#,
+        r#"AUTHORITY["EPSG","4289"]],PROJECTION["Oblique_Stereographic"],"#,
+        r#"PARAMETER["latitude_of_origin",52.1561605555556],"#,
+        r#"PARAMETER["central_meridian",5.38763888888889],"#,
+        r#"PARAMETER["scale_factor",0.9999079],PARAMETER["false_easting",155000],"#,
+        r#"PARAMETER["false_northing",463000],UNIT["metre",1,AUTHORITY["EPSG","9001"]],"#,
+        r#"AXIS["Easting",EAST],AXIS["Northing",NORTH],AUTHORITY["EPSG","28992"]]"#,
+    );
+
+    pub const WKT_GEODCRS_WGS84_GEOCENTRIC: &str = concat!(
+        r#"GEODCRS["WGS 84",DATUM["World Geodetic System 1984","#,
+        r#"ELLIPSOID["WGS 84",6378137,298.257223563,LENGTHUNIT["metre",1]]],"#,
+        r#"PRIMEM["Greenwich",0,ANGLEUNIT["degree",0.0174532925199433]],"#,
+        r#"CS[Cartesian,3],AXIS["(X)",geocentricX,ORDER[1],LENGTHUNIT["metre",1]],"#,
+        r#"AXIS["(Y)",geocentricY,ORDER[2],LENGTHUNIT["metre",1]],"#,
+        r#"AXIS["(Z)",geocentricZ,ORDER[3],LENGTHUNIT["metre",1]],ID["EPSG",4978]]"#,
+    );
+
+    pub const WKT_COMPOUNDCRS_NAD83_NAVD88: &str = concat!(
+        r#"COMPOUNDCRS["NAD83 + NAVD88 height","#,
+        r#"GEOGCRS["NAD83",DATUM["North American Datum 1983","#,
+        r#"ELLIPSOID["GRS 1980",6378137,298.257222101,LENGTHUNIT["metre",1]]],"#,
+        r#"PRIMEM["Greenwich",0,ANGLEUNIT["degree",0.0174532925199433]],"#,
+        r#"CS[ellipsoidal,2],AXIS["geodetic latitude (Lat)",north],"#,
+        r#"AXIS["geodetic longitude (Lon)",east],ANGLEUNIT["degree",0.0174532925199433]],"#,
+        r#"VERTCRS["NAVD88 height",VDATUM["North American Vertical Datum 1988"],"#,
+        r#"CS[vertical,1],AXIS["gravity-related height (H)",up,LENGTHUNIT["metre",1]],"#,
+        r#"GEOIDMODEL["GEOID12B",ID["EPSG",6636]],ID["EPSG",5703]]]"#,
+    );
+
+    pub const WKT_PROJCRS_GUAM: &str = concat!(
+        r#"PROJCRS["Guam 1963 / Guam SPCS",BASEGEOGCRS["Guam 1963",DATUM["Guam 1963","#,
+        r#"ELLIPSOID["Clarke 1866",6378206.4,294.978698213898,LENGTHUNIT["metre",1]]],"#,
+        r#"PRIMEM["Greenwich",0,ANGLEUNIT["degree",0.0174532925199433]],ID["EPSG",4675]],"#,
+        r#"CONVERSION["Guam SPCS",METHOD["Guam Projection",ID["EPSG",9831]],"#,
+        r#"PARAMETER["Latitude of natural origin",13.4724663527778,"#,
+        r#"ANGLEUNIT["degree",0.0174532925199433],ID["EPSG",8801]],"#,
+        r#"PARAMETER["Longitude of natural origin",144.748750705556,"#,
+        r#"ANGLEUNIT["degree",0.0174532925199433],ID["EPSG",8802]],"#,
+        r#"PARAMETER["False easting",50000,LENGTHUNIT["metre",1],ID["EPSG",8806]],"#,
+        r#"PARAMETER["False northing",50000,LENGTHUNIT["metre",1],ID["EPSG",8807]]],"#,
+        r#"CS[Cartesian,2],AXIS["easting (X)",east,ORDER[1],LENGTHUNIT["metre",1]],"#,
+        r#"AXIS["northing (Y)",north,ORDER[2],LENGTHUNIT["metre",1]],ID["EPSG",3993]]"#,
+    );
+
+    pub const WKT_PROJCS_NTF_LAMBERT_II: &str = concat!(
+        r#"PROJCS["NTF (Paris) / Lambert zone II",GEOGCS["NTF (Paris)","#,
+        r#"DATUM["Nouvelle_Triangulation_Francaise_Paris","#,
+        r#"SPHEROID["Clarke 1880 (IGN)",6378249.2,293.4660212936269,AUTHORITY["EPSG","7011"]],"#,
+        r#"TOWGS84[-168,-60,320,0,0,0,0],AUTHORITY["EPSG","6807"]],"#,
+        r#"PRIMEM["Paris",2.33722917,AUTHORITY["EPSG","8903"]],"#,
+        r#"UNIT["grad",0.01570796326794897,AUTHORITY["EPSG","9105"]],AUTHORITY["EPSG","4807"]],"#,
+        r#"PROJECTION["Lambert_Conformal_Conic_1SP"],PARAMETER["latitude_of_origin",52],"#,
+        r#"PARAMETER["central_meridian",0],PARAMETER["scale_factor",0.99987742],"#,
+        r#"PARAMETER["false_easting",600000],PARAMETER["false_northing",2200000],"#,
+        r#"UNIT["metre",1,AUTHORITY["EPSG","9001"]],AXIS["X",EAST],AXIS["Y",NORTH],"#,
+        r#"AUTHORITY["EPSG","27572"]]"#,
+    );
+
+    pub const WKT_PROJCS_TRINIDAD_GRID: &str = concat!(
+        r#"PROJCS["Trinidad 1903 / Trinidad Grid",GEOGCS["Trinidad 1903","#,
+        r#"DATUM["Trinidad_1903",SPHEROID["Clarke 1858",6378293.645208759,294.2606763692654,"#,
+        r#"AUTHORITY["EPSG","7007"]],TOWGS84[-61.702,284.488,472.052,0,0,0,0],"#,
+        r#"AUTHORITY["EPSG","6302"]],PRIMEM["Greenwich",0,AUTHORITY["EPSG","8901"]],"#,
+        r#"UNIT["degree",0.0174532925199433,AUTHORITY["EPSG","9122"]],AUTHORITY["EPSG","4302"]],"#,
+        r#"PROJECTION["Cassini_Soldner"],PARAMETER["latitude_of_origin",10.4416666666667],"#,
+        r#"PARAMETER["central_meridian",-61.3333333333333],PARAMETER["false_easting",430000],"#,
+        r#"PARAMETER["false_northing",325000],UNIT["Clarke's link",0.201166195164,"#,
+        r#"AUTHORITY["EPSG","9039"]],AXIS["Easting",EAST],AXIS["Northing",NORTH],"#,
+        r#"AUTHORITY["EPSG","30200"]]"#,
+    );
+
+    pub const WKT_PROJCS_MICHIGAN_OBLIQUE_MERCATOR: &str = concat!(
+        r#"PROJCS["NAD83 / Michigan Oblique Mercator",GEOGCS["NAD83","#,
+        r#"DATUM["North_American_Datum_1983",SPHEROID["GRS 1980",6378137,298.257222101,"#,
+        r#"AUTHORITY["EPSG","7019"]],AUTHORITY["EPSG","6269"]],PRIMEM["Greenwich",0,"#,
+        r#"AUTHORITY["EPSG","8901"]],UNIT["degree",0.0174532925199433,AUTHORITY["EPSG","9122"]],"#,
+        r#"AUTHORITY["EPSG","4269"]],PROJECTION["Hotine_Oblique_Mercator"],"#,
+        r#"PARAMETER["latitude_of_center",45.3091666666667],PARAMETER["longitude_of_center",-86],"#,
+        r#"PARAMETER["azimuth",337.25556],PARAMETER["rectified_grid_angle",337.25556],"#,
+        r#"PARAMETER["scale_factor",0.9996],PARAMETER["false_easting",2546731.496],"#,
+        r#"PARAMETER["false_northing",-4354009.816],UNIT["metre",1,AUTHORITY["EPSG","9001"]],"#,
+        r#"AXIS["Easting",EAST],AXIS["Northing",NORTH],AUTHORITY["EPSG","3078"]]"#,
+    );
+
+    pub const WKT_PROJCRS_ALASKA_ZONE_1: &str = concat!(
+        r#"PROJCRS["NAD83 / Alaska zone 1",BASEGEOGCRS["NAD83","#,
+        r#"DATUM["North American Datum 1983","#,
+        r#"ELLIPSOID["GRS 1980",6378137,298.257222101,LENGTHUNIT["metre",1]]],"#,
+        r#"PRIMEM["Greenwich",0,ANGLEUNIT["degree",0.0174532925199433]],ID["EPSG",4269]],"#,
+        r#"CONVERSION["SPCS83 Alaska zone 1 (meters)","#,
+        r#"METHOD["Hotine Oblique Mercator (variant B)",ID["EPSG",9815]],"#,
+        r#"PARAMETER["Latitude of projection centre",57,ANGLEUNIT["degree",0.0174532925199433],"#,
+        r#"ID["EPSG",8811]],PARAMETER["Longitude of projection centre",-133.666666666667,"#,
+        r#"ANGLEUNIT["degree",0.0174532925199433],ID["EPSG",8812]],"#,
+        r#"PARAMETER["Azimuth of initial line",323.130102361111,"#,
+        r#"ANGLEUNIT["degree",0.0174532925199433],ID["EPSG",8813]],"#,
+        r#"PARAMETER["Angle from Rectified to Skew Grid",323.130102361111,"#,
+        r#"ANGLEUNIT["degree",0.0174532925199433],ID["EPSG",8814]],"#,
+        r#"PARAMETER["Scale factor on initial line",0.9999,SCALEUNIT["unity",1],ID["EPSG",8815]],"#,
+        r#"PARAMETER["Easting at projection centre",5000000,LENGTHUNIT["metre",1],ID["EPSG",8816]],"#,
+        r#"PARAMETER["Northing at projection centre",-5000000,LENGTHUNIT["metre",1],"#,
+        r#"ID["EPSG",8817]]],CS[Cartesian,2],AXIS["easting (X)",east,ORDER[1],"#,
+        r#"LENGTHUNIT["metre",1]],AXIS["northing (Y)",north,ORDER[2],LENGTHUNIT["metre",1]],"#,
+        r#"ID["EPSG",26931]]"#,
+    );
+
+    pub const WKT2_2019_PROJCRS_UTM32N: &str = concat!(
+        r#"PROJCRS["WGS 84 / UTM zone 32N","#,
+        r#"BASEGEOGCRS["WGS 84","#,
+        r#"ENSEMBLE["World Geodetic System 1984 ensemble","#,
+        r#"MEMBER["World Geodetic System 1984 (Transit)"],"#,
+        r#"MEMBER["World Geodetic System 1984 (G730)"],"#,
+        r#"MEMBER["World Geodetic System 1984 (G873)"],"#,
+        r#"MEMBER["World Geodetic System 1984 (G1150)"],"#,
+        r#"MEMBER["World Geodetic System 1984 (G1674)"],"#,
+        r#"MEMBER["World Geodetic System 1984 (G1762)"],"#,
+        r#"MEMBER["World Geodetic System 1984 (G2139)"],"#,
+        r#"ELLIPSOID["WGS 84",6378137,298.257223563,LENGTHUNIT["metre",1]],"#,
+        r#"ENSEMBLEACCURACY[2.0]],"#,
+        r#"PRIMEM["Greenwich",0,ANGLEUNIT["degree",0.0174532925199433]],"#,
+        r#"ID["EPSG",4326]],"#,
+        r#"CONVERSION["UTM zone 32N","#,
+        r#"METHOD["Transverse Mercator",ID["EPSG",9807]],"#,
+        r#"PARAMETER["Latitude of natural origin",0,"#,
+        r#"ANGLEUNIT["degree",0.0174532925199433],ID["EPSG",8801]],"#,
+        r#"PARAMETER["Longitude of natural origin",9,"#,
+        r#"ANGLEUNIT["degree",0.0174532925199433],ID["EPSG",8802]],"#,
+        r#"PARAMETER["Scale factor at natural origin",0.9996,"#,
+        r#"SCALEUNIT["unity",1],ID["EPSG",8805]],"#,
+        r#"PARAMETER["False easting",500000,LENGTHUNIT["metre",1],ID["EPSG",8806]],"#,
+        r#"PARAMETER["False northing",0,LENGTHUNIT["metre",1],ID["EPSG",8807]]],"#,
+        r#"CS[Cartesian,2],"#,
+        r#"AXIS["(E)",east,ORDER[1],LENGTHUNIT["metre",1]],"#,
+        r#"AXIS["(N)",north,ORDER[2],LENGTHUNIT["metre",1]],"#,
+        r#"USAGE[SCOPE["Navigation and medium accuracy spatial referencing."],"#,
+        r#"AREA["Between 6°E and 12°E, northern hemisphere between equator and 84°N, "#,
+        r#"onshore and offshore. Algeria. Austria. Cameroon. Denmark. Equatorial Guinea. "#,
+        r#"France. Gabon. Germany. Italy. Libya. Liechtenstein. Monaco. Netherlands. "#,
+        r#"Niger. Nigeria. Norway. Sao Tome and Principe. Svalbard. Sweden. Switzerland. "#,
+        r#"Tunisia. Vatican City State."],"#,
+        r#"BBOX[0,6,84,12]],"#,
+        r#"ID["EPSG",32632],"#,
+        r#"REMARK["Projected ""UTM"" zone for the WGS 84 ensemble."]]"#,
+    );
+
+    pub const WKT_PROJCRS_SJTSK_KROVAK_EAST_NORTH: &str = concat!(
+        r#"PROJCRS["S-JTSK / Krovak East North",BASEGEOGCRS["S-JTSK","#,
+        r#"DATUM["System of the Unified Trigonometrical Cadastral Network","#,
+        r#"ELLIPSOID["Bessel 1841",6377397.155,299.1528128,LENGTHUNIT["metre",1]]],"#,
+        r#"PRIMEM["Greenwich",0,ANGLEUNIT["degree",0.0174532925199433]],ID["EPSG",4156]],"#,
+        r#"CONVERSION["Krovak East North (Greenwich)","#,
+        r#"METHOD["Krovak (North Orientated)",ID["EPSG",1041]],"#,
+        r#"PARAMETER["Latitude of projection centre",49.5,"#,
+        r#"ANGLEUNIT["degree",0.0174532925199433],ID["EPSG",8811]],"#,
+        r#"PARAMETER["Longitude of origin",24.8333333333333,"#,
+        r#"ANGLEUNIT["degree",0.0174532925199433],ID["EPSG",8833]],"#,
+        r#"PARAMETER["Co-latitude of cone axis",30.2881397527778,"#,
+        r#"ANGLEUNIT["degree",0.0174532925199433],ID["EPSG",1036]],"#,
+        r#"PARAMETER["Latitude of pseudo standard parallel",78.5,"#,
+        r#"ANGLEUNIT["degree",0.0174532925199433],ID["EPSG",8818]],"#,
+        r#"PARAMETER["Scale factor on pseudo standard parallel",0.9999,"#,
+        r#"SCALEUNIT["unity",1],ID["EPSG",8819]],"#,
+        r#"PARAMETER["False easting",0,LENGTHUNIT["metre",1],ID["EPSG",8806]],"#,
+        r#"PARAMETER["False northing",0,LENGTHUNIT["metre",1],ID["EPSG",8807]]],"#,
+        r#"CS[Cartesian,2],AXIS["easting (X)",east,ORDER[1],LENGTHUNIT["metre",1]],"#,
+        r#"AXIS["northing (Y)",north,ORDER[2],LENGTHUNIT["metre",1]],"#,
+        r#"USAGE[SCOPE["Engineering survey, topographic mapping."],"#,
+        r#"AREA["Czechia; Slovakia."],BBOX[47.73,12.09,51.06,22.56]],ID["EPSG",5514]]"#,
+    );
+
+    pub const WKT_PROJCS_SJTSK_KROVAK: &str = concat!(
+        r#"PROJCS["S-JTSK / Krovak",GEOGCS["S-JTSK",DATUM["System_Jednotne_Trigonometricke_Site_Katastralni","#,
+        r#"SPHEROID["Bessel 1841",6377397.155,299.1528128,AUTHORITY["EPSG","7004"]],"#,
+        r#"AUTHORITY["EPSG","6156"]],PRIMEM["Greenwich",0,AUTHORITY["EPSG","8901"]],"#,
+        r#"UNIT["degree",0.0174532925199433,AUTHORITY["EPSG","9122"]],AUTHORITY["EPSG","4156"]],"#,
+        r#"PROJECTION["Krovak"],PARAMETER["latitude_of_center",49.5],"#,
+        r#"PARAMETER["longitude_of_center",24.8333333333333],PARAMETER["azimuth",30.2881397527778],"#,
+        r#"PARAMETER["pseudo_standard_parallel_1",78.5],PARAMETER["scale_factor",0.9999],"#,
+        r#"PARAMETER["false_easting",0],PARAMETER["false_northing",0],"#,
+        r#"UNIT["metre",1,AUTHORITY["EPSG","9001"]],AXIS["X",SOUTH],AXIS["Y",WEST],"#,
+        r#"AUTHORITY["EPSG","5513"]]"#,
+    );
+
+    pub const WKT_PROJCS_SJTSK_KROVAK_NO_AXIS: &str = concat!(
+        r#"PROJCS["S-JTSK / Krovak",GEOGCS["S-JTSK",DATUM["System_Jednotne_Trigonometricke_Site_Katastralni","#,
+        r#"SPHEROID["Bessel 1841",6377397.155,299.1528128,AUTHORITY["EPSG","7004"]],"#,
+        r#"AUTHORITY["EPSG","6156"]],PRIMEM["Greenwich",0,AUTHORITY["EPSG","8901"]],"#,
+        r#"UNIT["degree",0.0174532925199433,AUTHORITY["EPSG","9122"]],AUTHORITY["EPSG","4156"]],"#,
+        r#"PROJECTION["Krovak"],PARAMETER["latitude_of_center",49.5],"#,
+        r#"PARAMETER["longitude_of_center",24.8333333333333],PARAMETER["azimuth",30.2881397527778],"#,
+        r#"PARAMETER["pseudo_standard_parallel_1",78.5],PARAMETER["scale_factor",0.9999],"#,
+        r#"PARAMETER["false_easting",0],PARAMETER["false_northing",0],"#,
+        r#"UNIT["metre",1,AUTHORITY["EPSG","9001"]]]"#,
+    );
+
+    pub const WKT_PROJCRS_MICHIGAN_NORTH: &str = concat!(
+        r#"PROJCRS["NAD27 / Michigan North",BASEGEOGCRS["NAD27","#,
+        r#"DATUM["North American Datum 1927","#,
+        r#"ELLIPSOID["Clarke 1866",6378206.4,294.978698213898,LENGTHUNIT["metre",1]]],"#,
+        r#"PRIMEM["Greenwich",0,ANGLEUNIT["degree",0.0174532925199433]],ID["EPSG",4267]],"#,
+        r#"CONVERSION["Michigan North zone (US survey foot)","#,
+        r#"METHOD["Lambert Conic Conformal (2SP Michigan)",ID["EPSG",1051]],"#,
+        r#"PARAMETER["Latitude of false origin",44.7833333333333,"#,
+        r#"ANGLEUNIT["degree",0.0174532925199433],ID["EPSG",8821]],"#,
+        r#"PARAMETER["Longitude of false origin",-87,"#,
+        r#"ANGLEUNIT["degree",0.0174532925199433],ID["EPSG",8822]],"#,
+        r#"PARAMETER["Latitude of 1st standard parallel",45.4833333333333,"#,
+        r#"ANGLEUNIT["degree",0.0174532925199433],ID["EPSG",8823]],"#,
+        r#"PARAMETER["Latitude of 2nd standard parallel",47.0833333333333,"#,
+        r#"ANGLEUNIT["degree",0.0174532925199433],ID["EPSG",8824]],"#,
+        r#"PARAMETER["Easting at false origin",2000000,"#,
+        r#"LENGTHUNIT["US survey foot",0.304800609601219],ID["EPSG",8826]],"#,
+        r#"PARAMETER["Northing at false origin",0,"#,
+        r#"LENGTHUNIT["US survey foot",0.304800609601219],ID["EPSG",8827]],"#,
+        r#"PARAMETER["Ellipsoid scaling factor",1.0000382,SCALEUNIT["unity",1],ID["EPSG",1038]]],"#,
+        r#"CS[Cartesian,2],AXIS["easting (X)",east,ORDER[1],"#,
+        r#"LENGTHUNIT["US survey foot",0.304800609601219]],"#,
+        r#"AXIS["northing (Y)",north,ORDER[2],LENGTHUNIT["US survey foot",0.304800609601219]],"#,
+        r#"ID["EPSG",6966]]"#,
+    );
+
+    pub const WKT_PROJCS_PSEUDO_MERCATOR: &str = concat!(
+        r#"PROJCS["WGS 84 / Pseudo-Mercator",GEOGCS["Popular Visualisation CRS","#,
+        r#"DATUM["Popular_Visualisation_Datum",SPHEROID["Popular Visualisation Sphere",6378137,0,"#,
+        r#"AUTHORITY["EPSG","7059"]],TOWGS84[0,0,0,0,0,0,0],AUTHORITY["EPSG","6055"]],"#,
+        r#"PRIMEM["Greenwich",0,AUTHORITY["EPSG","8901"]],"#,
+        r#"UNIT["degree",0.0174532925199433,AUTHORITY["EPSG","9122"]],AUTHORITY["EPSG","4055"]],"#,
+        r#"PROJECTION["Popular_Visualisation_Pseudo_Mercator"],PARAMETER["latitude_of_origin",0],"#,
+        r#"PARAMETER["central_meridian",0],PARAMETER["false_easting",0],"#,
+        r#"PARAMETER["false_northing",0],UNIT["metre",1,AUTHORITY["EPSG","9001"]],"#,
+        r#"AXIS["X",EAST],AXIS["Y",NORTH],AUTHORITY["EPSG","3857"]]"#,
+    );
+
+    pub const WKT_GEOGCS_NAD27_FEET: &str = concat!(
+        r#"GEOGCS["NAD27",DATUM["North_American_Datum_1927","#,
+        r#"SPHEROID["Clarke 1866",20925832.164,294.9786982,"#,
+        r#"UNIT["US survey foot",0.304800609601219]]],"#,
+        r#"PRIMEM["Greenwich",0],UNIT["degree",0.0174532925199433]]"#,
+    );
 }
 
 #[test]
@@ -48,6 +387,38 @@ fn build_parameter() {
     );
 }
 
+#[test]
+fn build_parameter_unit_type() {
+    setup();
+    let unit_type = |wkt| match Builder::new().parse(wkt).unwrap() {
+        Node::PARAMETER(p) => p.unit.map(|u| u.unit_type),
+        _ => panic!("Expecting PARAMETER"),
+    };
+
+    assert_eq!(
+        unit_type(concat!(
+            r#"PARAMETER["Latitude of natural origin",52,"#,
+            r#"ANGLEUNIT["grad",0.015707963267948966],ID["EPSG",8801]]"#,
+        )),
+        Some(UnitType::Angular)
+    );
+    assert_eq!(
+        unit_type(concat!(
+            r#"PARAMETER["False easting",600000,"#,
+            r#"LENGTHUNIT["metre",1],ID["EPSG",8806]]"#,
+        )),
+        Some(UnitType::Linear)
+    );
+    assert_eq!(
+        unit_type(r#"PARAMETER["Scale factor",0.9996,SCALEUNIT["unity",1]]"#),
+        Some(UnitType::Scale)
+    );
+    assert_eq!(
+        unit_type(r#"PARAMETER["central_meridian",3,UNIT["degree",0.0174532925199433]]"#),
+        Some(UnitType::Unknown)
+    );
+}
+
 #[test]
 fn build_ellipsoid() {
     setup();
@@ -60,6 +431,10 @@ fn build_ellipsoid() {
             a: "6378137",
             rf: "298.257222101",
             unit: None,
+            authority: Some(Authority {
+                name: "EPSG",
+                code: "7019",
+            }),
         })
     );
 }
@@ -88,6 +463,10 @@ fn build_unit() {
             name: "degree",
             factor: 0.01745329251994328,
             unit_type: UnitType::Unknown,
+            authority: Some(Authority {
+                name: "EPSG",
+                code: "9122",
+            }),
         })
     );
 }
@@ -109,8 +488,18 @@ fn build_datum() {
                 a: "6378137",
                 rf: "298.257222101",
                 unit: None,
+                authority: Some(Authority {
+                    name: "EPSG",
+                    code: "7019",
+                }),
             },
-            to_wgs84: vec![],
+            to_wgs84: ToWgs84::default(),
+            has_explicit_towgs84: false,
+            nadgrids: None,
+            authority: Some(Authority {
+                name: "EPSG",
+                code: "6269",
+            }),
         })
     );
 }
@@ -135,7 +524,7 @@ fn build_nad83() {
     println!("{r:#?}");
     assert_eq!(
         r,
-        Node::PROJCRS(Projcs {
+        Node::PROJCRS(Box::new(Projcs {
             name: "NAD83 / Massachusetts Mainland",
             geogcs: Geogcs {
                 name: "NAD83",
@@ -146,14 +535,40 @@ fn build_nad83() {
                         a: "6378137",
                         rf: "298.257222101",
                         unit: None,
+                        authority: Some(Authority {
+                            name: "EPSG",
+                            code: "7019",
+                        }),
                     },
-                    to_wgs84: vec![],
+                    to_wgs84: ToWgs84::default(),
+                    has_explicit_towgs84: false,
+                    nadgrids: None,
+                    authority: Some(Authority {
+                        name: "EPSG",
+                        code: "6269",
+                    }),
                 },
                 unit: Some(Unit {
                     name: "degree",
                     factor: 0.01745329251994328,
                     unit_type: UnitType::Angular,
+                    authority: Some(Authority {
+                        name: "EPSG",
+                        code: "9122",
+                    }),
+                }),
+                prime_meridian: Some(PrimeMeridian {
+                    name: "Greenwich",
+                    longitude: 0.0,
+                    unit: None,
                 }),
+                cs: None,
+                usage: None,
+                authority: Some(Authority {
+                    name: "EPSG",
+                    code: "4269",
+                }),
+                remark: None,
             },
             projection: Projection {
                 name: "Unknown",
@@ -199,16 +614,2241 @@ fn build_nad83() {
                         authority: None,
                     }
                 ],
+                authority: None,
+            },
+            unit: Some(Unit {
+                name: "metre",
+                factor: 1.0,
+                unit_type: UnitType::Linear,
                 authority: Some(Authority {
                     name: "EPSG",
-                    code: "26986",
+                    code: "9001",
                 }),
-            },
+            }),
+            cs: None,
+            usage: None,
+            axes: vec![
+                Axis {
+                    name: "X",
+                    direction: AxisDirection::East,
+                    unit: None,
+                    order: None,
+                },
+                Axis {
+                    name: "Y",
+                    direction: AxisDirection::North,
+                    unit: None,
+                    order: None,
+                },
+            ],
+            authority: Some(Authority {
+                name: "EPSG",
+                code: "26986",
+            }),
+            remark: None,
+        })),
+    );
+}
+
+#[test]
+fn build_wgs84_invalid_value() {
+    setup();
+    let wkt = r#"TOWGS84[59.47,-5.04,1e999]"#;
+    let r = Builder::new().parse(wkt);
+    assert!(r.is_err());
+
+    let err = Builder::new().process(
+        "TOWGS84",
+        0,
+        vec![Attribute::Number("59.47"), Attribute::Number("foo")].into_iter(),
+    );
+    assert_eq!(
+        err.unwrap_err().to_string(),
+        "WKT error: invalid TOWGS84 parameter at position 1"
+    );
+}
+
+#[test]
+fn geogcs_prime_meridian_longitude() {
+    setup();
+    let r = Builder::new().parse(fixtures::WKT_GEOGCS_WGS84).unwrap();
+    let Node::GEOGCRS(cs) = r else {
+        panic!("Expecting GEOGCRS");
+    };
+    assert_eq!(cs.prime_meridian_longitude(), 0.0);
+
+    let r = Builder::new()
+        .parse(fixtures::WKT_GEOGCS_NTF_PARIS)
+        .unwrap();
+    let Node::GEOGCRS(cs) = r else {
+        panic!("Expecting GEOGCRS");
+    };
+    approx::assert_abs_diff_eq!(cs.prime_meridian_longitude(), 2.3372, epsilon = 1e-4);
+}
+
+#[test]
+fn build_prime_meridian_angle_unit() {
+    setup();
+    let wkt = r#"PRIMEM["Paris",2.5969213,ANGLEUNIT["grad",0.0157079632679489]]"#;
+    let Node::PRIMEM(pm) = Builder::new().parse(wkt).unwrap() else {
+        panic!("Expecting PRIMEM");
+    };
+    assert_eq!(pm.longitude, 2.5969213);
+    assert_eq!(
+        pm.unit.as_ref().map(|u| &u.unit_type),
+        Some(&UnitType::Angular)
+    );
+    approx::assert_abs_diff_eq!(pm.longitude_degrees(), 2.33722917, epsilon = 1e-8);
+}
+
+#[test]
+fn datum_wgs84_shift_params() {
+    setup();
+    let datum = |name, to_wgs84| Datum {
+        name,
+        ellipsoid: Ellipsoid {
+            name: "GRS 1980",
+            a: "6378137",
+            rf: "298.257222101",
+            unit: None,
+            authority: None,
+        },
+        to_wgs84: ToWgs84::from(to_wgs84),
+        has_explicit_towgs84: false,
+        nadgrids: None,
+        authority: None,
+    };
+
+    assert_eq!(datum("Unknown", vec![]).wgs84_shift_params(), None);
+    assert_eq!(
+        datum("WGS_1984", vec![]).wgs84_shift_params(),
+        Some([0.0; 7])
+    );
+    assert_eq!(
+        datum("Unknown", vec!["-87", "-98", "-121"]).wgs84_shift_params(),
+        Some([-87.0, -98.0, -121.0, 0.0, 0.0, 0.0, 0.0])
+    );
+    assert_eq!(
+        datum(
+            "Unknown",
+            vec!["446.448", "-125.157", "542.06", "0.15", "0.247", "0.842", "-20.489"]
+        )
+        .wgs84_shift_params(),
+        Some([446.448, -125.157, 542.06, 0.15, 0.247, 0.842, -20.489])
+    );
+}
+
+#[test]
+fn conversion_report() {
+    setup();
+    let (_, report) = crate::wkt_to_projstring_with_report(fixtures::WKT_PROJCS_NAD83).unwrap();
+    assert_eq!(report.method_match, Some(MatchKind::Name));
+    assert_eq!(report.parameters.len(), 6);
+    assert!(report.dropped_parameters.is_empty());
+    assert!(!report.is_high_confidence());
+
+    let (_, report) = crate::wkt_to_projstring_with_report(fixtures::WKT_PROJCRS_GUAM).unwrap();
+    assert_eq!(report.method_match, Some(MatchKind::Epsg));
+    assert!(report
+        .parameters
+        .iter()
+        .all(|(_, kind)| *kind == MatchKind::Epsg));
+    assert!(report.is_high_confidence());
+
+    let (_, report) = crate::wkt_to_projstring_with_report(fixtures::WKT_GEOGCS_WGS84).unwrap();
+    assert_eq!(report, Report::default());
+}
+
+#[test]
+fn convert_amersfoort_rd_new() {
+    setup();
+    let r = Builder::new()
+        .parse(fixtures::WKT_PROJCS_AMERSFOORT)
+        .unwrap();
+    assert!(matches!(r, Node::PROJCRS(_)));
+
+    let projstr = crate::wkt_to_projstring(fixtures::WKT_PROJCS_AMERSFOORT).unwrap();
+    assert_eq!(
+        projstr,
+        concat!(
+            "+proj=sterea +lat_0=52.1561605555556 +lon_0=5.38763888888889 +k=0.9999079",
+            " +x_0=155000 +y_0=463000 +units=m +a=6377397.155 +rf=299.1528128",
+            " +towgs84=565.2369,50.0087,465.658,-0.406857,0.350733,-1.87035,4.0812",
+        )
+    );
+}
+
+#[test]
+fn node_crs_name() {
+    setup();
+    let crs_name = |wkt: &str| {
+        Builder::new()
+            .parse(wkt)
+            .unwrap()
+            .crs_name()
+            .map(String::from)
+    };
+
+    assert_eq!(
+        crs_name(fixtures::WKT_PROJCS_NAD83),
+        Some("NAD83 / Massachusetts Mainland".into())
+    );
+    assert_eq!(crs_name(fixtures::WKT_GEOGCS_WGS84), Some("WGS 84".into()));
+    assert_eq!(
+        crs_name(fixtures::WKT_COMPOUNDCRS_NAD83_NAVD88),
+        Some("NAD83 + NAVD88 height".into())
+    );
+    assert_eq!(crs_name(r#"AUTHORITY["EPSG","26986"]"#), None);
+}
+
+#[test]
+fn projection_find_parameter() {
+    setup();
+    let r = Builder::new().parse(fixtures::WKT_PROJCS_NAD83).unwrap();
+    let Node::PROJCRS(cs) = r else {
+        panic!("Expecting PROJCRS");
+    };
+
+    let projection = &cs.projection;
+    assert_eq!(
+        projection
+            .find_parameter("standard_parallel_1")
+            .map(|p| p.value),
+        Some("42.68333333333333")
+    );
+    assert_eq!(
+        projection.find_parameter("False_Easting").map(|p| p.value),
+        Some("200000")
+    );
+    assert!(projection.find_parameter("scale_factor").is_none());
+
+    let wkt = concat!(
+        r#"CONVERSION["SPCS83 Massachusetts Mainland zone (meter)","#,
+        r#"METHOD["Lambert Conic Conformal (2SP)",ID["EPSG",9802]],"#,
+        r#"PARAMETER["Latitude of 1st standard parallel",42.6833333333333,"#,
+        r#"ANGLEUNIT["degree",0.0174532925199433],ID["EPSG",8823]],"#,
+        r#"PARAMETER["Easting at false origin",200000,"#,
+        r#"LENGTHUNIT["metre",1],ID["EPSG",8826]]]"#,
+    );
+    let Node::PROJECTION(projection) = Builder::new().parse(wkt).unwrap() else {
+        panic!("Expecting PROJECTION");
+    };
+    assert_eq!(
+        projection.find_parameter_by_epsg("8823").map(|p| p.value),
+        Some("42.6833333333333")
+    );
+    assert_eq!(
+        projection.find_parameter_by_epsg("8826").map(|p| p.value),
+        Some("200000")
+    );
+    assert!(projection.find_parameter_by_epsg("8824").is_none());
+}
+
+#[test]
+fn build_angular_unit() {
+    setup();
+    let wkt = r#"ANGULARUNIT["degree",0.0174532925199433]"#;
+    let r = Builder::new().parse(wkt).unwrap();
+    assert_eq!(
+        r,
+        Node::UNIT(Unit {
+            name: "degree",
+            factor: 0.0174532925199433,
+            unit_type: UnitType::Angular,
+            authority: None,
+        })
+    );
+
+    let wkt = concat!(
+        r#"GEOGCRS["WGS 84",DATUM["World Geodetic System 1984","#,
+        r#"ELLIPSOID["WGS 84",6378137,298.257223563,LENGTHUNIT["metre",1]]],"#,
+        r#"CS[ellipsoidal,2],AXIS["latitude",north],AXIS["longitude",east],"#,
+        r#"ANGULARUNIT["degree",0.0174532925199433]]"#,
+    );
+    let Node::GEOGCRS(cs) = Builder::new().parse(wkt).unwrap() else {
+        panic!("Expecting GEOGCRS");
+    };
+    assert_eq!(
+        cs.unit,
+        Some(Unit {
+            name: "degree",
+            factor: 0.0174532925199433,
+            unit_type: UnitType::Angular,
+            authority: None,
+        })
+    );
+}
+
+#[test]
+fn build_geographic_from_base_geodetic() {
+    setup();
+    let wkt = concat!(
+        r#"GEOGRAPHICCRS["WGS 84 (G1762)",BASEGEODCRS["WGS 84",DATUM["World Geodetic System 1984","#,
+        r#"ELLIPSOID["WGS 84",6378137,298.257223563,LENGTHUNIT["metre",1]]],"#,
+        r#"PRIMEM["Greenwich",0,ANGLEUNIT["degree",0.0174532925199433]]],"#,
+        r#"CS[ellipsoidal,2],AXIS["latitude",north,ORDER[1]],"#,
+        r#"AXIS["longitude",east,ORDER[2]],ANGLEUNIT["degree",0.0174532925199433]]"#,
+    );
+    let Node::GEOGCRS(cs) = Builder::new().parse(wkt).unwrap() else {
+        panic!("Expecting GEOGCRS");
+    };
+    assert_eq!(cs.name, "WGS 84 (G1762)");
+    assert_eq!(cs.datum.name, "World Geodetic System 1984");
+    assert_eq!(cs.datum.ellipsoid.a, "6378137");
+    assert_eq!(cs.prime_meridian.map(|pm| pm.name), Some("Greenwich"));
+
+    assert_eq!(
+        crate::wkt_to_projstring(wkt).unwrap(),
+        "+proj=longlat +a=6378137 +rf=298.257223563 +towgs84=0,0,0,0,0,0,0"
+    );
+}
+
+#[test]
+fn build_geocentric() {
+    setup();
+    let r = Builder::new()
+        .parse(fixtures::WKT_GEODCRS_WGS84_GEOCENTRIC)
+        .unwrap();
+    let Node::GEOCCRS(cs) = r else {
+        panic!("Expecting GEOCCRS");
+    };
+    assert_eq!(
+        cs.cs,
+        Some(CoordinateSystem {
+            cs_type: CsType::Cartesian,
+            dimension: 3,
+        })
+    );
+
+    let wkt = concat!(
+        r#"GEOCCS["WGS 84",DATUM["WGS_1984",SPHEROID["WGS 84",6378137,298.257223563]],"#,
+        r#"PRIMEM["Greenwich",0],UNIT["metre",1],AXIS["Geocentric X",OTHER],"#,
+        r#"AXIS["Geocentric Y",OTHER],AXIS["Geocentric Z",NORTH]]"#,
+    );
+    let Node::GEOCCRS(cs) = Builder::new().parse(wkt).unwrap() else {
+        panic!("Expecting GEOCCRS");
+    };
+    assert_eq!(cs.unit.map(|u| u.unit_type), Some(UnitType::Linear));
+
+    // Dimension is an integer in [1, 3]
+    for dimension in ["2.7", "-1", "0", "4", "256"] {
+        let err = Builder::new().process(
+            "CS",
+            1,
+            vec![Attribute::Label("Cartesian"), Attribute::Number(dimension)].into_iter(),
+        );
+        assert_eq!(
+            err.unwrap_err().to_string(),
+            format!("WKT error: Invalid CS dimension: {dimension}")
+        );
+    }
+}
+
+#[test]
+fn build_time_unit() {
+    setup();
+    let wkt = r#"TIMEUNIT["year",31556925.445]"#;
+    let r = Builder::new().parse(wkt).unwrap();
+    assert_eq!(
+        r,
+        Node::UNIT(Unit {
+            name: "year",
+            factor: 31556925.445,
+            unit_type: UnitType::Unknown,
+            authority: None,
+        })
+    );
+
+    let wkt = concat!(
+        r#"COMPOUNDCRS["WGS 84 + EGM96 height + GPS time","#,
+        r#"GEOGCRS["WGS 84",DATUM["World Geodetic System 1984","#,
+        r#"ELLIPSOID["WGS 84",6378137,298.257223563,LENGTHUNIT["metre",1]]],"#,
+        r#"CS[ellipsoidal,2],AXIS["latitude",north],AXIS["longitude",east],"#,
+        r#"ANGLEUNIT["degree",0.0174532925199433]],"#,
+        r#"VERTCRS["EGM96 height",VDATUM["EGM96 geoid"],CS[vertical,1],"#,
+        r#"AXIS["gravity-related height (H)",up,LENGTHUNIT["metre",1]]],"#,
+        r#"TIMECRS["GPS Time",TDATUM["Time origin",TIMEORIGIN["1980-01-01"]],"#,
+        r#"CS[temporal,1],AXIS["time",future],TIMEUNIT["day",86400.0]]]"#,
+    );
+    let r = Builder::new().parse(wkt).unwrap();
+    assert!(matches!(r, Node::COMPOUNDCRS(_)));
+    assert_eq!(
+        crate::wkt_to_projstring(wkt).unwrap(),
+        "+proj=longlat +a=6378137 +rf=298.257223563 +towgs84=0,0,0,0,0,0,0"
+    );
+}
+
+#[test]
+fn build_usage() {
+    setup();
+    let wkt = concat!(
+        r#"USAGE[SCOPE["Horizontal component of 3D system."],"#,
+        r#"AREA["World."],BBOX[-90,-180,90,180]]"#,
+    );
+    let r = Builder::new().parse(wkt).unwrap();
+    assert_eq!(
+        r,
+        Node::USAGE(UsageInfo {
+            scope: Some("Horizontal component of 3D system."),
+            area: Some("World."),
+            bounding_box: Some([-90.0, -180.0, 90.0, 180.0]),
+        })
+    );
+}
+
+#[test]
+fn build_from_bytes() {
+    setup();
+    let wkt = fixtures::WKT_GEOGCS_WGS84.as_bytes();
+    let r = Builder::new().parse_bytes(wkt).unwrap();
+    assert_eq!(r, Builder::new().parse(fixtures::WKT_GEOGCS_WGS84).unwrap());
+
+    // With BOM
+    let bytes = [b"\xEF\xBB\xBF", wkt].concat();
+    let r = Builder::new().parse_bytes(&bytes).unwrap();
+    assert!(matches!(r, Node::GEOGCRS(_)));
+
+    // Invalid UTF-8
+    let bytes = [wkt, b"\xFF"].concat();
+    assert!(matches!(
+        Builder::new().parse_bytes(&bytes),
+        Err(crate::errors::Error::Utf8(_))
+    ));
+}
+
+#[test]
+fn build_geoid_model() {
+    setup();
+    let Node::COMPOUNDCRS(crs) = Builder::new()
+        .parse(fixtures::WKT_COMPOUNDCRS_NAD83_NAVD88)
+        .unwrap()
+    else {
+        panic!("Expecting COMPOUNDCRS");
+    };
+    assert_eq!(
+        crs.v_crs,
+        Verticalcrs {
+            name: "NAVD88 height",
+            geoid_model: Some(GeoidModel {
+                name: "GEOID12B",
+                authority: Some(Authority {
+                    name: "EPSG",
+                    code: "6636",
+                }),
+            }),
             unit: Some(Unit {
                 name: "metre",
                 factor: 1.0,
                 unit_type: UnitType::Linear,
+                authority: None,
             }),
-        }),
+            authority: Some(Authority {
+                name: "EPSG",
+                code: "5703",
+            }),
+            remark: None,
+        }
+    );
+}
+
+#[test]
+fn round_trip_fixtures() {
+    setup();
+    assert_round_trip(
+        fixtures::WKT_PROJCS_NAD83,
+        concat!(
+            "+proj=lcc +lat_1=42.68333333333333 +lat_2=41.71666666666667",
+            " +lat_0=-41 +lon_0=-71.5 +x_0=200000 +y_0=750000 +units=m +a=6378137",
+            " +rf=298.257222101 +towgs84=0,0,0,0,0,0,0",
+        ),
+    );
+    assert_round_trip(
+        fixtures::WKT_GEOGCS_WGS84,
+        "+proj=longlat +a=6378137 +rf=298.257223563 +towgs84=0,0,0,0,0,0,0",
     );
+    assert_round_trip(
+        fixtures::WKT_GEOGCS_NTF_PARIS,
+        concat!(
+            "+proj=longlat +a=6378249.2 +rf=293.4660212936269",
+            " +towgs84=0,0,0,0,0,0,0 +pm=paris",
+        ),
+    );
+    assert_round_trip(
+        fixtures::WKT_PROJCS_OSGB36,
+        concat!(
+            "+proj=tmerc +lat_0=49 +lon_0=-2 +k=0.9996012717 +x_0=400000 +y_0=-100000",
+            " +units=m +a=6377563.396 +rf=299.3249646",
+            " +towgs84=446.448,-125.157,542.060,0.1502,0.2470,0.8421,-20.4894",
+        ),
+    );
+    assert_round_trip(
+        fixtures::WKT_GEODCRS_WGS84_GEOCENTRIC,
+        "+proj=geocent +a=6378137 +rf=298.257223563 +towgs84=0,0,0,0,0,0,0 +units=m",
+    );
+    assert_round_trip(
+        fixtures::WKT_PROJCRS_GUAM,
+        concat!(
+            "+proj=aeqd +lat_0=13.4724663527778 +lon_0=144.748750705556",
+            " +x_0=50000 +y_0=50000 +units=m +a=6378206.4 +rf=294.978698213898",
+            " +towgs84=0,0,0,0,0,0,0 +guam",
+        ),
+    );
+}
+
+#[test]
+fn valid_projstring() {
+    assert!(is_valid_projstring("+proj=longlat +no_defs"));
+    assert!(!is_valid_projstring(""));
+    assert!(!is_valid_projstring("+proj=longlat  +a=1"));
+    assert!(!is_valid_projstring("proj=longlat"));
+    assert!(!is_valid_projstring("+proj="));
+}
+
+mod proptests {
+    use super::{fixtures, is_valid_projstring};
+    use crate::wkt_to_projstring;
+    use proptest::prelude::*;
+
+    fn check_no_panic(wkt: &str) {
+        if let Ok(s) = wkt_to_projstring(wkt) {
+            assert!(!s.is_empty());
+            assert!(is_valid_projstring(&s), "Invalid proj string: {s}");
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn arbitrary_input_never_panics(s in "\\PC*") {
+            check_no_panic(&s);
+        }
+
+        #[test]
+        fn wkt_like_input_never_panics(
+            s in r#"[A-Z_]{1,8}\[("[a-z ]{0,5}"|-?[0-9]{1,4}(\.[0-9]{1,3})?|[A-Z]{1,8}\[[0-9,]{0,8}\]|,){0,12}\]"#
+        ) {
+            check_no_panic(&s);
+        }
+
+        #[test]
+        fn truncated_fixture_never_panics(n in 0..fixtures::WKT_PROJCS_NAD83.len()) {
+            check_no_panic(&fixtures::WKT_PROJCS_NAD83[..n]);
+        }
+
+        #[test]
+        fn altered_fixture_never_panics(
+            n in 0..fixtures::WKT_PROJCRS_GUAM.len(),
+            c in prop::sample::select(vec!["[", "]", ",", "\"", "1", "X", ""]),
+        ) {
+            let wkt = fixtures::WKT_PROJCRS_GUAM;
+            check_no_panic(&format!("{}{}{}", &wkt[..n], c, &wkt[n + 1..]));
+        }
+    }
+}
+
+#[test]
+fn conversion_diagnostics() {
+    setup();
+    let wkt = concat!(
+        r#"PROJCS["Test",GEOGCS["WGS 84",DATUM["WGS_1984","#,
+        r#"SPHEROID["WGS 84",6378137,298.257223563]],PRIMEM["Greenwich",0],"#,
+        r#"UNIT["degree",0.0174532925199433]],PROJECTION["Transverse_Mercator"],"#,
+        r#"PARAMETER["latitude_of_origin",0],PARAMETER["central_meridian",3],"#,
+        r#"PARAMETER["scale_factor",0.9996],PARAMETER["false_easting",500000],"#,
+        r#"PARAMETER["false_northing",0],PARAMETER["projection_plane_origin_height",200],"#,
+        r#"UNIT["metre",1]]"#,
+    );
+    let (projstr, diagnostics) = crate::wkt_to_projstring_with_diagnostics(wkt).unwrap();
+    assert!(!projstr.contains("200 "));
+    assert_eq!(
+        diagnostics.warnings(),
+        ["Parameter 'projection_plane_origin_height' has no proj mapping and was ignored"]
+    );
+
+    let (_, diagnostics) =
+        crate::wkt_to_projstring_with_diagnostics(fixtures::WKT_PROJCS_NAD83).unwrap();
+    assert!(diagnostics.is_empty());
+}
+
+#[test]
+fn build_error_chain() {
+    setup();
+    let wkt = concat!(
+        r#"GEOGCS["WGS 84",DATUM["WGS_1984",SPHEROID["WGS 84",6378137]],"#,
+        r#"PRIMEM["Greenwich",0],UNIT["degree",0.0174532925199433]]"#,
+    );
+    let err = Builder::new().parse(wkt).unwrap_err();
+
+    let chain: Vec<_> = std::iter::successors(Some(&err as &dyn std::error::Error), |e| e.source())
+        .map(|e| e.to_string())
+        .collect();
+    assert_eq!(
+        chain,
+        [
+            "WKT error: Invalid GEOGCS",
+            "WKT error: Invalid DATUM",
+            "WKT error: Invalid SPHEROID inverse flattening",
+        ]
+    );
+
+    // Syntax error
+    assert!(matches!(
+        Builder::new().parse(r#"GEOGCS("WGS 84")"#),
+        Err(crate::Error::Parse)
+    ));
+}
+
+#[test]
+fn builder_is_shareable() {
+    fn assert_send_sync<T: Send + Sync>(_: &T) {}
+
+    let builder = Builder::new();
+    assert_send_sync(&builder);
+    std::thread::scope(|s| {
+        s.spawn(|| builder.parse(fixtures::WKT_PROJCS_NAD83).unwrap());
+        s.spawn(|| builder.parse(fixtures::WKT_PROJCS_OSGB36).unwrap());
+    });
+}
+
+#[test]
+fn conversion_verbose() {
+    setup();
+    let errors = crate::wkt_to_projstring_verbose(concat!(
+        r#"PROJCS["Test",GEOGCS["WGS 84",DATUM["WGS_1984","#,
+        r#"SPHEROID["WGS 84",6378137,298.257223563]],PRIMEM["Greenwich",0],"#,
+        r#"UNIT["degree",0.0174532925199433]],PROJECTION["Transverse_Mercator"],"#,
+        r#"PARAMETER["foo",1],PARAMETER["central_meridian",3],"#,
+        r#"PARAMETER["Longitude of natural origin",9],UNIT["metre",1]]"#,
+    ))
+    .unwrap_err();
+    assert_eq!(errors.len(), 2);
+    assert_eq!(
+        errors[0].to_string(),
+        "WKT error: Parameter 'foo' has no proj mapping and was ignored"
+    );
+    assert_eq!(
+        errors[1].to_string(),
+        "WKT error: Conflicting values for parameter 'Longitude of natural origin'"
+    );
+
+    // Builder warnings come first
+    let errors = crate::wkt_to_projstring_verbose(concat!(
+        r#"PROJCS["Test",GEOGCS["WGS 84",DATUM["WGS_1984","#,
+        r#"SPHEROID["WGS 84",6378137,298.257223563]],PRIMEM["Greenwich",0],"#,
+        r#"UNIT["degree",0.0174532925199433]],PROJECTION["Transverse_Mercator"],"#,
+        r#"PARAMETER["central_meridian",1],PARAMETER["foo",1],PARAMETER["central_meridian",3],"#,
+        r#"PARAMETER["Longitude of natural origin",9],UNIT["metre",1]]"#,
+    ))
+    .unwrap_err();
+    assert_eq!(
+        errors.iter().map(|err| err.to_string()).collect::<Vec<_>>(),
+        [
+            "WKT error: Duplicate parameter 'central_meridian', value 1 was overridden",
+            "WKT error: Parameter 'foo' has no proj mapping and was ignored",
+            "WKT error: Conflicting values for parameter 'Longitude of natural origin'",
+        ]
+    );
+
+    assert!(crate::wkt_to_projstring_verbose(fixtures::WKT_PROJCS_NAD83).is_ok());
+}
+
+#[test]
+fn convert_lcc_1sp_as_proj() {
+    setup();
+    // Output of `projinfo -o PROJ EPSG:27572`
+    const PROJ_LCC_1SP: &str = concat!(
+        "+proj=lcc +lat_1=46.8 +lat_0=46.8 +lon_0=0 +k_0=0.99987742 +x_0=600000",
+        " +y_0=2200000 +a=6378249.2 +b=6356515 +towgs84=-168,-60,320,0,0,0,0",
+        " +pm=paris +units=m +no_defs +type=crs",
+    );
+
+    let projstr = crate::wkt_to_projstring(fixtures::WKT_PROJCS_NTF_LAMBERT_II).unwrap();
+
+    let param = |s: &str, name: &str| {
+        s.split(' ')
+            .find_map(|t| t.strip_prefix('+')?.strip_prefix(name)?.strip_prefix('='))
+            .map(str::to_string)
+    };
+    for name in ["proj", "pm", "towgs84"] {
+        assert_eq!(param(&projstr, name), param(PROJ_LCC_1SP, name), "{name}");
+    }
+    for name in ["lat_1", "lat_0", "lon_0", "k_0", "x_0", "y_0"] {
+        let value = |s| crate::builder::parse_number(&param(s, name).unwrap()).unwrap();
+        approx::assert_abs_diff_eq!(value(&projstr), value(PROJ_LCC_1SP), epsilon = 1e-12);
+    }
+}
+
+#[test]
+fn projstring_to_wkt_round_trip() {
+    setup();
+    // Compare proj strings regardless of parameters order
+    let tokens = |s: &str| {
+        let mut v = s.split(' ').map(str::to_string).collect::<Vec<_>>();
+        v.sort();
+        v
+    };
+
+    for wkt in [
+        fixtures::WKT_PROJCS_NAD83,
+        fixtures::WKT_GEOGCS_WGS84,
+        fixtures::WKT_GEOGCS_NTF_PARIS,
+        fixtures::WKT_PROJCS_OSGB36,
+    ] {
+        let projstr = crate::wkt_to_projstring(wkt).unwrap();
+        let wkt = crate::projstring_to_wkt(&projstr).unwrap();
+        assert_eq!(
+            tokens(&crate::wkt_to_projstring(&wkt).unwrap()),
+            tokens(&projstr),
+            "{wkt}"
+        );
+    }
+
+    for projstr in [
+        concat!(
+            "+proj=aea +lat_0=23 +lon_0=-96 +lat_1=29.5 +lat_2=45.5 +x_0=0 +y_0=0",
+            " +units=m +a=6378137 +rf=298.257222101 +towgs84=0,0,0,0,0,0,0",
+        ),
+        concat!(
+            "+proj=laea +lat_0=52 +lon_0=10 +x_0=4321000 +y_0=3210000",
+            " +units=m +a=6378137 +rf=298.257222101 +towgs84=0,0,0,0,0,0,0",
+        ),
+        concat!(
+            "+proj=merc +lon_0=0 +k=1 +x_0=0 +y_0=0",
+            " +units=m +a=6378137 +rf=298.257223563 +towgs84=0,0,0,0,0,0,0",
+        ),
+        concat!(
+            "+proj=tmerc +lat_0=0 +lon_0=-81 +k=0.9996 +x_0=500000 +y_0=0",
+            " +to_meter=0.3048 +a=6378206.4 +rf=294.9786982139006 +towgs84=-8,160,176",
+        ),
+    ] {
+        let wkt = crate::projstring_to_wkt(projstr).unwrap();
+        assert_eq!(
+            tokens(&crate::wkt_to_projstring(&wkt).unwrap()),
+            tokens(projstr),
+            "{wkt}"
+        );
+    }
+
+    // Known datums are named so that their shift is kept
+    for projstr in [
+        "+proj=longlat +datum=NAD27",
+        "+proj=longlat +datum=WGS84",
+        "+proj=tmerc +lat_0=0 +lon_0=-81 +k=0.9996 +x_0=500000 +y_0=0 +units=m +datum=NAD83",
+    ] {
+        let wkt = crate::projstring_to_wkt(projstr).unwrap();
+        let options = crate::ConversionOptions::new().use_datum_names(true);
+        assert_eq!(
+            tokens(&crate::wkt_to_projstring_with_options(&wkt, options).unwrap()),
+            tokens(projstr),
+            "{wkt}"
+        );
+    }
+    let wkt = crate::projstring_to_wkt("+proj=longlat +datum=NAD27").unwrap();
+    assert_eq!(
+        crate::wkt_to_projstring(&wkt).unwrap(),
+        concat!(
+            "+proj=longlat +a=6378206.4 +rf=294.9786982139006",
+            " +nadgrids=@conus,@alaska,@ntv2_0.gsb,@ntv1_can.dat",
+        )
+    );
+}
+
+#[test]
+fn projstring_to_wkt_emit() {
+    setup();
+    assert_eq!(
+        crate::projstring_to_wkt("+proj=longlat +datum=WGS84 +no_defs").unwrap(),
+        concat!(
+            r#"GEOGCS["unknown",DATUM["WGS_1984",SPHEROID["unknown",6378137,298.257223563]],"#,
+            r#"PRIMEM["Greenwich",0],UNIT["degree",0.0174532925199433]]"#,
+        )
+    );
+    assert_eq!(
+        crate::projstring_to_wkt("+proj=tmerc +lon_0=3 +k=0.9996 +ellps=GRS80").unwrap(),
+        concat!(
+            r#"PROJCS["unknown",GEOGCS["unknown","#,
+            r#"DATUM["unknown",SPHEROID["unknown",6378137,298.257222101]],"#,
+            r#"PRIMEM["Greenwich",0],UNIT["degree",0.0174532925199433]],"#,
+            r#"PROJECTION["Transverse_Mercator"],PARAMETER["latitude_of_origin",0],"#,
+            r#"PARAMETER["central_meridian",3],PARAMETER["scale_factor",0.9996],"#,
+            r#"PARAMETER["false_easting",0],PARAMETER["false_northing",0],UNIT["metre",1]]"#,
+        )
+    );
+
+    assert!(crate::projstring_to_wkt("+lat_0=0 +ellps=GRS80").is_err());
+    assert!(crate::projstring_to_wkt("+proj=longlat").is_err());
+    assert!(crate::projstring_to_wkt("+proj=foo +ellps=GRS80").is_err());
+    // Unrepresentable datum shifts
+    assert!(crate::projstring_to_wkt("+proj=longlat +ellps=GRS80 +datum=foo").is_err());
+    assert!(crate::projstring_to_wkt("+proj=longlat +ellps=GRS80 +towgs84=1,2").is_err());
+}
+
+#[test]
+fn conversion_proj_params() {
+    setup();
+    let params = crate::wkt_to_proj_params(fixtures::WKT_PROJCS_NAD83).unwrap();
+    let projstr = crate::wkt_to_projstring(fixtures::WKT_PROJCS_NAD83).unwrap();
+
+    let tokens = params
+        .iter()
+        .map(|(k, v)| format!("+{k}={v}"))
+        .collect::<Vec<_>>();
+    assert_eq!(tokens.join(" "), projstr);
+
+    // Verbatim values borrow from the input
+    let (_, lat_1) = params.into_iter().find(|(k, _)| *k == "lat_1").unwrap();
+    assert!(matches!(lat_1, Cow::Borrowed("42.68333333333333")));
+}
+
+#[test]
+fn build_coordinate_metadata() {
+    setup();
+    let wkt = format!(
+        r#"COORDINATEMETADATA[{},EPOCH[2016.47]]"#,
+        fixtures::WKT_PROJCS_NAD83
+    );
+    let node = Builder::new().parse(&wkt).unwrap();
+    let Node::COORDINATEMETADATA(md) = &node else {
+        panic!("Expecting COORDINATEMETADATA, found {node:?}");
+    };
+    assert_eq!(md.epoch, Some(2016.47));
+    assert_eq!(
+        node.crs_name().as_deref(),
+        Some("NAD83 / Massachusetts Mainland")
+    );
+
+    assert_eq!(
+        crate::wkt_to_projstring(&wkt).unwrap(),
+        crate::wkt_to_projstring(fixtures::WKT_PROJCS_NAD83).unwrap(),
+    );
+}
+
+#[test]
+fn build_geogcs_authority() {
+    setup();
+    let Node::GEOGCRS(cs) = Builder::new().parse(fixtures::WKT_GEOGCS_WGS84).unwrap() else {
+        panic!("Expecting GEOGCRS");
+    };
+    assert_eq!(
+        cs.authority,
+        Some(Authority {
+            name: "EPSG",
+            code: "4326"
+        })
+    );
+
+    let Node::GEOCCRS(cs) = Builder::new()
+        .parse(fixtures::WKT_GEODCRS_WGS84_GEOCENTRIC)
+        .unwrap()
+    else {
+        panic!("Expecting GEOCCRS");
+    };
+    assert_eq!(
+        cs.authority,
+        Some(Authority {
+            name: "EPSG",
+            code: "4978"
+        })
+    );
+}
+
+#[test]
+fn conversion_with_kind() {
+    use crate::{ConversionOptions, CrsKind, Formatter};
+    setup();
+
+    let mut buf = String::new();
+    let mut formatter =
+        Formatter::new_string(&mut buf).options(ConversionOptions::new().precision(6));
+
+    for (wkt, expected) in [
+        (fixtures::WKT_GEOGCS_WGS84, CrsKind::Geographic),
+        (fixtures::WKT_PROJCS_NAD83, CrsKind::Projected),
+        (fixtures::WKT_COMPOUNDCRS_NAD83_NAVD88, CrsKind::Compound),
+        (fixtures::WKT_GEODCRS_WGS84_GEOCENTRIC, CrsKind::Geocentric),
+    ] {
+        let node = Builder::new().parse(wkt).unwrap();
+        let (kind, projstr) = formatter.format_with_kind(&node).unwrap();
+        assert_eq!(kind, expected);
+        assert!(projstr.starts_with("+proj="));
+    }
+
+    let node = Builder::new().parse(fixtures::WKT_PROJCS_NAD83).unwrap();
+    let (_, projstr) = formatter.format_with_kind(&node).unwrap();
+    assert!(projstr.contains(" +lat_1=42.6833 "));
+    assert!(formatter.report().method_match.is_some());
+
+    // Nothing is written to the formatter output
+    drop(formatter);
+    assert!(buf.is_empty());
+}
+
+#[test]
+fn convert_arc_second_parameters() {
+    use crate::projstr_parser::ProjString;
+    setup();
+
+    let arc_second = |name| Unit {
+        name,
+        factor: 4.84813681109536e-06,
+        unit_type: UnitType::Angular,
+        authority: Some(Authority {
+            name: "EPSG",
+            code: "9104",
+        }),
+    };
+    assert!(!arc_second("arc-second").is_degree());
+    // EPSG code takes precedence over the name
+    assert!(!arc_second("degree").is_degree());
+
+    let projstr = crate::wkt_to_projstring(concat!(
+        r#"PROJCRS["Test",BASEGEOGCRS["WGS 84",DATUM["World Geodetic System 1984","#,
+        r#"ELLIPSOID["WGS 84",6378137,298.257223563,LENGTHUNIT["metre",1]]],"#,
+        r#"PRIMEM["Greenwich",0,ANGLEUNIT["degree",0.0174532925199433]]],"#,
+        r#"CONVERSION["Test",METHOD["Transverse Mercator",ID["EPSG",9807]],"#,
+        r#"PARAMETER["Latitude of natural origin",180000,"#,
+        r#"ANGLEUNIT["arc-second",4.84813681109536E-06,ID["EPSG",9104]],ID["EPSG",8801]],"#,
+        r#"PARAMETER["Longitude of natural origin",540,"#,
+        r#"ANGLEUNIT["arc-minute",0.000290888208665722,ID["EPSG",9103]],ID["EPSG",8802]],"#,
+        r#"PARAMETER["Scale factor at natural origin",0.9996,SCALEUNIT["unity",1],ID["EPSG",8805]],"#,
+        r#"PARAMETER["False easting",500000,LENGTHUNIT["metre",1],ID["EPSG",8806]],"#,
+        r#"PARAMETER["False northing",0,LENGTHUNIT["metre",1],ID["EPSG",8807]]],"#,
+        r#"CS[Cartesian,2],AXIS["(E)",east,ORDER[1],LENGTHUNIT["metre",1]],"#,
+        r#"AXIS["(N)",north,ORDER[2],LENGTHUNIT["metre",1]]]"#,
+    ))
+    .unwrap();
+
+    let ps = ProjString::parse(&projstr).unwrap();
+    assert!((ps.get_number("lat_0").unwrap().unwrap() - 50.0).abs() < 1e-9);
+    assert!((ps.get_number("lon_0").unwrap().unwrap() - 9.0).abs() < 1e-9);
+}
+
+#[test]
+fn extract_projcs_authority() {
+    setup();
+    assert_eq!(
+        crate::wkt_extract_projcs_authority(fixtures::WKT_PROJCS_NAD83).unwrap(),
+        Some(("EPSG", "26986"))
+    );
+    // WKT2
+    let Node::PROJCRS(cs) = Builder::new().parse(fixtures::WKT_PROJCRS_GUAM).unwrap() else {
+        panic!("Expecting PROJCRS");
+    };
+    assert_eq!(
+        cs.authority,
+        Some(Authority {
+            name: "EPSG",
+            code: "3993"
+        })
+    );
+    assert_eq!(cs.projection.authority, None);
+    assert!(crate::wkt_extract_projcs_authority(fixtures::WKT_GEOGCS_WGS84).is_err());
+}
+
+#[test]
+fn iterate_projection_parameters() {
+    setup();
+    let Node::PROJCRS(cs) = Builder::new().parse(fixtures::WKT_PROJCS_NAD83).unwrap() else {
+        panic!("Expecting PROJCRS");
+    };
+
+    let projection = &cs.projection;
+    assert_eq!(projection.len(), 6);
+    assert!(!projection.is_empty());
+
+    let mut names = vec![];
+    for param in projection {
+        names.push(param.name);
+    }
+    assert_eq!(
+        names,
+        [
+            "standard_parallel_1",
+            "standard_parallel_2",
+            "latitude_of_origin",
+            "central_meridian",
+            "false_easting",
+            "false_northing",
+        ]
+    );
+
+    let Node::DATUM(datum) = Builder::new()
+        .parse(concat!(
+            r#"DATUM["Nouvelle_Triangulation_Francaise",SPHEROID["Clarke 1880 (IGN)","#,
+            r#"6378249.2,293.4660212936269],TOWGS84[-168,-60,320,0,0,0,0]]"#,
+        ))
+        .unwrap()
+    else {
+        panic!("Expecting DATUM");
+    };
+    let to_wgs84 = &datum.to_wgs84;
+    assert_eq!(to_wgs84.len(), 7);
+    let mut values = vec![];
+    for v in to_wgs84 {
+        values.push(v);
+    }
+    assert_eq!(values, ["-168", "-60", "320", "0", "0", "0", "0"]);
+}
+
+#[test]
+fn build_usage_metadata() {
+    setup();
+    let wkt = concat!(
+        r#"GEOGCRS["WGS 84",DATUM["World Geodetic System 1984","#,
+        r#"ELLIPSOID["WGS 84",6378137,298.257223563,LENGTHUNIT["metre",1]]],"#,
+        r#"CS[ellipsoidal,2],AXIS["latitude",north],AXIS["longitude",east],"#,
+        r#"ANGLEUNIT["degree",0.0174532925199433],"#,
+        r#"USAGE[SCOPE["Horizontal component of 3D system."],AREA["World."],"#,
+        r#"BBOX[-90,-180,90,180],VERTICALEXTENT[-1000,0,LENGTHUNIT["metre",1]],"#,
+        r#"TEMPORALEXTENT["2002-01-01","2022-12-31"]],REMARK["Test ""remark"""],ID["EPSG",4326]]"#,
+    );
+    let Node::GEOGCRS(cs) = Builder::new().parse(wkt).unwrap() else {
+        panic!("Expecting GEOGCRS");
+    };
+    assert_eq!(cs.bounding_box(), Some([-90.0, -180.0, 90.0, 180.0]));
+    assert_eq!(cs.usage.as_ref().and_then(|u| u.area), Some("World."));
+    assert_eq!(cs.remark, Some(r#"Test ""remark"""#));
+    assert_eq!(
+        cs.authority,
+        Some(Authority {
+            name: "EPSG",
+            code: "4326"
+        })
+    );
+
+    // WKT2 2015: usage at crs level
+    let wkt = concat!(
+        r#"GEODCRS["WGS 84",DATUM["World Geodetic System 1984","#,
+        r#"ELLIPSOID["WGS 84",6378137,298.257223563,LENGTHUNIT["metre",1]]],"#,
+        r#"CS[ellipsoidal,2],AXIS["latitude",north],AXIS["longitude",east],"#,
+        r#"ANGLEUNIT["degree",0.0174532925199433],SCOPE["Horizontal component of 3D system."],"#,
+        r#"AREA["World."],BBOX[-90,-180,90,180]]"#,
+    );
+    let Node::GEOGCRS(cs) = Builder::new().parse(wkt).unwrap() else {
+        panic!("Expecting GEOGCRS");
+    };
+    assert_eq!(cs.bounding_box(), Some([-90.0, -180.0, 90.0, 180.0]));
+    assert_eq!(
+        cs.usage.as_ref().and_then(|u| u.scope),
+        Some("Horizontal component of 3D system.")
+    );
+}
+
+#[test]
+fn node_bounding_box() {
+    setup();
+    let projcrs = concat!(
+        r#"PROJCRS["WGS 84 / UTM zone 31N",BASEGEOGCRS["WGS 84","#,
+        r#"DATUM["World Geodetic System 1984","#,
+        r#"ELLIPSOID["WGS 84",6378137,298.257223563,LENGTHUNIT["metre",1]]]],"#,
+        r#"CONVERSION["UTM zone 31N",METHOD["Transverse Mercator",ID["EPSG",9807]],"#,
+        r#"PARAMETER["Latitude of natural origin",0,ID["EPSG",8801]],"#,
+        r#"PARAMETER["Longitude of natural origin",3,ID["EPSG",8802]],"#,
+        r#"PARAMETER["Scale factor at natural origin",0.9996,ID["EPSG",8805]],"#,
+        r#"PARAMETER["False easting",500000,ID["EPSG",8806]],"#,
+        r#"PARAMETER["False northing",0,ID["EPSG",8807]]],"#,
+        r#"CS[Cartesian,2],AXIS["(E)",east],AXIS["(N)",north],LENGTHUNIT["metre",1],"#,
+        r#"USAGE[SCOPE["Navigation"],AREA["Between 0°E and 6°E"],BBOX[0,0,84,6]]]"#,
+    );
+    let node = Builder::new().parse(projcrs).unwrap();
+    assert_eq!(node.bounding_box(), Some([0.0, 0.0, 84.0, 6.0]));
+
+    let vertcrs = concat!(
+        r#"VERTCRS["EGM96 height",VDATUM["EGM96 geoid"],"#,
+        r#"CS[vertical,1],AXIS["gravity-related height (H)",up,LENGTHUNIT["metre",1]]]"#,
+    );
+
+    // Fall back to horizontal crs
+    let wkt = format!(r#"COMPOUNDCRS["WGS 84 / UTM zone 31N + EGM96 height",{projcrs},{vertcrs}]"#);
+    let node = Builder::new().parse(&wkt).unwrap();
+    assert_eq!(node.bounding_box(), Some([0.0, 0.0, 84.0, 6.0]));
+
+    let wkt = format!(
+        r#"COMPOUNDCRS["WGS 84 / UTM zone 31N + EGM96 height",{projcrs},{vertcrs},{}]"#,
+        r#"USAGE[SCOPE["Test"],AREA["Test"],BBOX[40,0,50,6]]"#,
+    );
+    let node = Builder::new().parse(&wkt).unwrap();
+    assert_eq!(node.bounding_box(), Some([40.0, 0.0, 50.0, 6.0]));
+
+    let node = Builder::new().parse(fixtures::WKT_GEOGCS_WGS84).unwrap();
+    assert_eq!(node.bounding_box(), None);
+}
+
+#[test]
+fn node_remark() {
+    setup();
+    let wkt = concat!(
+        r#"VERTCRS["EGM96 height",VDATUM["EGM96 geoid"],"#,
+        r#"CS[vertical,1],AXIS["gravity-related height (H)",up,LENGTHUNIT["metre",1]],"#,
+        r#"REMARK["Use ""egm96_15"" grid"]]"#,
+    );
+    let node = Builder::new().parse(wkt).unwrap();
+    assert_eq!(node.remark().as_deref(), Some(r#"Use "egm96_15" grid"#));
+
+    let wkt = format!(r#"COORDINATEMETADATA[{wkt},EPOCH[2010.0]]"#);
+    let node = Builder::new().parse(&wkt).unwrap();
+    assert_eq!(node.remark().as_deref(), Some(r#"Use "egm96_15" grid"#));
+
+    let node = Builder::new().parse(fixtures::WKT_PROJCS_NAD83).unwrap();
+    assert_eq!(node.remark(), None);
+}
+
+#[test]
+fn projstring_to_wkt2_round_trip() {
+    setup();
+    // Bound crs are not parsed, datum shifts are compared
+    // on the emitted WKT only
+    let tokens = |s: &str| {
+        let mut v = s
+            .split(' ')
+            .filter(|t| !t.starts_with("+towgs84="))
+            .map(str::to_string)
+            .collect::<Vec<_>>();
+        v.sort();
+        v
+    };
+    let without_towgs84 = |s: &str| tokens(s).join(" ");
+
+    for wkt in [
+        fixtures::WKT_PROJCS_NAD83,
+        fixtures::WKT_GEOGCS_WGS84,
+        fixtures::WKT_GEOGCS_NTF_PARIS,
+        fixtures::WKT_PROJCS_OSGB36,
+    ] {
+        let projstr = without_towgs84(&crate::wkt_to_projstring(wkt).unwrap());
+        let wkt = crate::projstring_to_wkt2(&projstr).unwrap();
+        assert_eq!(
+            tokens(&crate::wkt_to_projstring(&wkt).unwrap()),
+            tokens(&projstr),
+            "{wkt}"
+        );
+    }
+
+    let projstr = concat!(
+        "+proj=tmerc +lat_0=0 +lon_0=-81 +k=0.9996 +x_0=500000 +y_0=0",
+        " +to_meter=0.3048 +a=6378206.4 +rf=294.9786982139006",
+    );
+    let wkt = crate::projstring_to_wkt2(projstr).unwrap();
+    assert_eq!(
+        tokens(&crate::wkt_to_projstring(&wkt).unwrap()),
+        tokens(projstr),
+        "{wkt}"
+    );
+
+    let wkt = crate::projstring_to_wkt2(&format!(
+        "{projstr} +towgs84=446.448,-125.157,542.06,0.15,0.247,0.842,-20.489"
+    ))
+    .unwrap();
+    assert!(
+        wkt.contains(r#"PARAMETER["Scale difference",0.999979511,"#),
+        "{wkt}"
+    );
+
+    // Known datums are named so that their shift is kept
+    for projstr in [
+        "+proj=longlat +datum=NAD27",
+        "+proj=tmerc +lat_0=0 +lon_0=-81 +k=0.9996 +x_0=500000 +y_0=0 +units=m +datum=NAD83",
+    ] {
+        let wkt = crate::projstring_to_wkt2(projstr).unwrap();
+        let options = crate::ConversionOptions::new().use_datum_names(true);
+        assert_eq!(
+            tokens(&crate::wkt_to_projstring_with_options(&wkt, options).unwrap()),
+            tokens(projstr),
+            "{wkt}"
+        );
+    }
+}
+
+#[test]
+fn projstring_to_wkt2_emit() {
+    setup();
+    assert_eq!(
+        crate::projstring_to_wkt2("+proj=tmerc +lon_0=3 +k=0.9996 +ellps=GRS80").unwrap(),
+        concat!(
+            r#"PROJCRS["unknown",BASEGEOGCRS["unknown",DATUM["unknown","#,
+            r#"ELLIPSOID["unknown",6378137,298.257222101,LENGTHUNIT["metre",1]]],"#,
+            r#"PRIMEM["Greenwich",0,ANGLEUNIT["degree",0.0174532925199433]]],"#,
+            r#"CONVERSION["unknown",METHOD["Transverse Mercator",ID["EPSG",9807]],"#,
+            r#"PARAMETER["Latitude of natural origin",0,"#,
+            r#"ANGLEUNIT["degree",0.0174532925199433],ID["EPSG",8801]],"#,
+            r#"PARAMETER["Longitude of natural origin",3,"#,
+            r#"ANGLEUNIT["degree",0.0174532925199433],ID["EPSG",8802]],"#,
+            r#"PARAMETER["Scale factor at natural origin",0.9996,SCALEUNIT["unity",1],ID["EPSG",8805]],"#,
+            r#"PARAMETER["False easting",0,LENGTHUNIT["metre",1],ID["EPSG",8806]],"#,
+            r#"PARAMETER["False northing",0,LENGTHUNIT["metre",1],ID["EPSG",8807]]],"#,
+            r#"CS[Cartesian,2],AXIS["(E)",east,ORDER[1],LENGTHUNIT["metre",1]],"#,
+            r#"AXIS["(N)",north,ORDER[2],LENGTHUNIT["metre",1]]]"#,
+        )
+    );
+
+    let wkt =
+        crate::projstring_to_wkt2("+proj=longlat +ellps=GRS80 +towgs84=1,2,3,4,5,6,7").unwrap();
+    assert!(wkt.starts_with(r#"BOUNDCRS[SOURCECRS[GEOGCRS["unknown","#));
+    assert!(wkt.contains(r#"ID["EPSG",9606]],PARAMETER["X-axis translation",1,ID["EPSG",8605]]"#));
+
+    assert!(crate::projstring_to_wkt2("+proj=longlat +ellps=GRS80 +towgs84=1,2").is_err());
+    assert!(crate::projstring_to_wkt2("+proj=foo +ellps=GRS80").is_err());
+    // Unrepresentable datum shifts
+    assert!(crate::projstring_to_wkt2("+proj=longlat +ellps=GRS80 +datum=foo").is_err());
+    assert!(crate::projstring_to_wkt2("+proj=longlat +ellps=GRS80 +nadgrids=ntf_r93.gsb").is_err());
+}
+
+#[test]
+fn build_projcs_keyword_order() {
+    setup();
+    const GEOGCS: &str = concat!(
+        r#"GEOGCS["NAD83",DATUM["North_American_Datum_1983","#,
+        r#"SPHEROID["GRS 1980",6378137,298.257222101]],"#,
+        r#"PRIMEM["Greenwich",0],UNIT["degree",0.0174532925199433]]"#,
+    );
+
+    let wkt = |body: &str| format!(r#"PROJCS["NAD83 / UTM zone 18N",{GEOGCS},{body}]"#);
+
+    // Standard order
+    let standard = wkt(concat!(
+        r#"PROJECTION["Transverse_Mercator"],"#,
+        r#"PARAMETER["latitude_of_origin",0],PARAMETER["central_meridian",-75],"#,
+        r#"PARAMETER["scale_factor",0.9996],PARAMETER["false_easting",500000],"#,
+        r#"PARAMETER["false_northing",0],UNIT["metre",1],AUTHORITY["EPSG","26918"]"#,
+    ));
+    let Node::PROJCRS(expected) = Builder::new().parse(&standard).unwrap() else {
+        panic!("Expecting PROJCRS");
+    };
+    assert_eq!(expected.projection.parameters.len(), 5);
+    assert_eq!(
+        expected.authority,
+        Some(Authority {
+            name: "EPSG",
+            code: "26918"
+        })
+    );
+
+    for body in [
+        // UNIT before PROJECTION
+        concat!(
+            r#"UNIT["metre",1],PROJECTION["Transverse_Mercator"],"#,
+            r#"PARAMETER["latitude_of_origin",0],PARAMETER["central_meridian",-75],"#,
+            r#"PARAMETER["scale_factor",0.9996],PARAMETER["false_easting",500000],"#,
+            r#"PARAMETER["false_northing",0],AUTHORITY["EPSG","26918"]"#,
+        ),
+        // PARAMETER interspersed with AUTHORITY
+        concat!(
+            r#"PROJECTION["Transverse_Mercator"],"#,
+            r#"PARAMETER["latitude_of_origin",0],PARAMETER["central_meridian",-75],"#,
+            r#"AUTHORITY["EPSG","26918"],PARAMETER["scale_factor",0.9996],"#,
+            r#"PARAMETER["false_easting",500000],PARAMETER["false_northing",0],"#,
+            r#"UNIT["metre",1]"#,
+        ),
+        // AUTHORITY before UNIT
+        concat!(
+            r#"PROJECTION["Transverse_Mercator"],"#,
+            r#"PARAMETER["latitude_of_origin",0],PARAMETER["central_meridian",-75],"#,
+            r#"PARAMETER["scale_factor",0.9996],PARAMETER["false_easting",500000],"#,
+            r#"PARAMETER["false_northing",0],AUTHORITY["EPSG","26918"],UNIT["metre",1]"#,
+        ),
+    ] {
+        let wkt = wkt(body);
+        let Node::PROJCRS(cs) = Builder::new().parse(&wkt).unwrap() else {
+            panic!("Expecting PROJCRS");
+        };
+        assert_eq!(cs, expected, "{body}");
+    }
+}
+
+#[test]
+fn build_axis_meridian() {
+    setup();
+    let Node::AXIS(axis) = Builder::new()
+        .parse(concat!(
+            r#"AXIS["Northing (N)",north,MERIDIAN[0,ANGLEUNIT["degree",0.0174532925199433]],"#,
+            r#"ORDER[1],LENGTHUNIT["metre",1]]"#,
+        ))
+        .unwrap()
+    else {
+        panic!("Expecting AXIS");
+    };
+    assert_eq!(axis.name, "Northing (N)");
+    assert_eq!(axis.direction, AxisDirection::Meridian(0.0));
+
+    // Polar stereographic south
+    let Node::PROJCRS(cs) = Builder::new()
+        .parse(concat!(
+            r#"PROJCRS["WGS 84 / Antarctic Polar Stereographic",BASEGEOGCRS["WGS 84","#,
+            r#"DATUM["World Geodetic System 1984",ELLIPSOID["WGS 84",6378137,298.257223563,"#,
+            r#"LENGTHUNIT["metre",1]]],PRIMEM["Greenwich",0,ANGLEUNIT["degree",0.0174532925199433]]],"#,
+            r#"CONVERSION["Antarctic Polar Stereographic",METHOD["Polar Stereographic (variant B)","#,
+            r#"ID["EPSG",9829]],PARAMETER["Latitude of standard parallel",-71,"#,
+            r#"ANGLEUNIT["degree",0.0174532925199433],ID["EPSG",8832]],"#,
+            r#"PARAMETER["Longitude of origin",0,ANGLEUNIT["degree",0.0174532925199433],ID["EPSG",8833]],"#,
+            r#"PARAMETER["False easting",0,LENGTHUNIT["metre",1],ID["EPSG",8806]],"#,
+            r#"PARAMETER["False northing",0,LENGTHUNIT["metre",1],ID["EPSG",8807]]],"#,
+            r#"CS[Cartesian,2],"#,
+            r#"AXIS["easting (E)",north,MERIDIAN[90,ANGLEUNIT["degree",0.0174532925199433]],"#,
+            r#"ORDER[1],LENGTHUNIT["metre",1]],"#,
+            r#"AXIS["northing (N)",north,MERIDIAN[0,ANGLEUNIT["grad",0.015707963267949]],"#,
+            r#"ORDER[2],LENGTHUNIT["metre",1]],ID["EPSG",3031]]"#,
+        ))
+        .unwrap()
+    else {
+        panic!("Expecting PROJCRS");
+    };
+    assert_eq!(cs.axes.len(), 2);
+    assert_eq!(cs.axes[0].direction, AxisDirection::Meridian(90.0));
+    assert_eq!(cs.axes[1].direction, AxisDirection::Meridian(0.0));
+
+    // Angle unit conversion
+    let Node::AXIS(axis) = Builder::new()
+        .parse(r#"AXIS["Easting (E)",south,MERIDIAN[100,ANGLEUNIT["grad",0.015707963267949]]]"#)
+        .unwrap()
+    else {
+        panic!("Expecting AXIS");
+    };
+    let AxisDirection::Meridian(m) = axis.direction else {
+        panic!("Expecting meridian direction");
+    };
+    assert!((m - 90.0).abs() < 1e-9);
+
+    // Named direction without meridian
+    let Node::AXIS(axis) = Builder::new().parse(r#"AXIS["Easting (E)",east]"#).unwrap() else {
+        panic!("Expecting AXIS");
+    };
+    assert_eq!(axis.direction, AxisDirection::East);
+}
+
+#[test]
+fn conversion_projection_authority() {
+    setup();
+    let Node::PROJCRS(cs) = Builder::new()
+        .parse(fixtures::WKT_PROJCS_OSGB36_PROJECTION_AUTHORITY)
+        .unwrap()
+    else {
+        panic!("Expecting PROJCRS");
+    };
+    assert_eq!(
+        cs.projection.method,
+        Method {
+            name: "Transverse_Mercator",
+            authority: Some(Authority {
+                name: "EPSG",
+                code: "9807"
+            }),
+        }
+    );
+
+    let (projstr, report) =
+        crate::wkt_to_projstring_with_report(fixtures::WKT_PROJCS_OSGB36_PROJECTION_AUTHORITY)
+            .unwrap();
+    assert_eq!(report.method_match, Some(MatchKind::Epsg));
+    assert_eq!(
+        projstr,
+        crate::wkt_to_projstring(fixtures::WKT_PROJCS_OSGB36).unwrap()
+    );
+
+    // Non EPSG authority falls back to the projection name
+    let wkt = fixtures::WKT_PROJCS_OSGB36_PROJECTION_AUTHORITY.replace(
+        r#"AUTHORITY["EPSG","9807"]"#,
+        r#"AUTHORITY["ESRI","43006"]"#,
+    );
+    let (converted, report) = crate::wkt_to_projstring_with_report(&wkt).unwrap();
+    assert_eq!(report.method_match, Some(MatchKind::Name));
+    assert_eq!(converted, projstr);
+}
+
+#[test]
+fn parse_crs_info() {
+    setup();
+    let info = crate::wkt_parse_info(fixtures::WKT_PROJCS_NAD83).unwrap();
+    assert_eq!(
+        info,
+        crate::CrsInfo {
+            name: "NAD83 / Massachusetts Mainland".into(),
+            crs_type: CrsKind::Projected,
+            authority: Some(("EPSG".into(), "26986".into())),
+            datum_name: Some("North_American_Datum_1983".into()),
+            ellipsoid_name: Some("GRS 1980".into()),
+            proj_string: Some(crate::wkt_to_projstring(fixtures::WKT_PROJCS_NAD83).unwrap()),
+        }
+    );
+
+    let info = crate::wkt_parse_info(fixtures::WKT_GEOGCS_WGS84).unwrap();
+    assert_eq!(info.crs_type, CrsKind::Geographic);
+    assert_eq!(info.name, "WGS 84");
+    assert_eq!(info.ellipsoid_name.as_deref(), Some("WGS 84"));
+    assert!(info.proj_string.is_some());
+
+    let info = crate::wkt_parse_info(fixtures::WKT_GEODCRS_WGS84_GEOCENTRIC).unwrap();
+    assert_eq!(info.crs_type, CrsKind::Geocentric);
+    assert_eq!(info.authority, Some(("EPSG".into(), "4978".into())));
+    assert_eq!(
+        info.datum_name.as_deref(),
+        Some("World Geodetic System 1984")
+    );
+
+    let info = crate::wkt_parse_info(fixtures::WKT_COMPOUNDCRS_NAD83_NAVD88).unwrap();
+    assert_eq!(info.crs_type, CrsKind::Compound);
+    assert_eq!(info.name, "NAD83 + NAVD88 height");
+    assert_eq!(info.authority, None);
+    assert_eq!(
+        info.datum_name.as_deref(),
+        Some("North American Datum 1983")
+    );
+    assert!(info.proj_string.is_some());
+
+    // Vertical crs has no proj string equivalent
+    let info = crate::wkt_parse_info(concat!(
+        r#"VERTCRS["NAVD88 height",VDATUM["North American Vertical Datum 1988"],"#,
+        r#"CS[vertical,1],AXIS["gravity-related height (H)",up,LENGTHUNIT["metre",1]],"#,
+        r#"ID["EPSG",5703]]"#,
+    ))
+    .unwrap();
+    assert_eq!(
+        info,
+        crate::CrsInfo {
+            name: "NAVD88 height".into(),
+            crs_type: CrsKind::Vertical,
+            authority: Some(("EPSG".into(), "5703".into())),
+            datum_name: None,
+            ellipsoid_name: None,
+            proj_string: None,
+        }
+    );
+
+    // Unsupported projection
+    let info = crate::wkt_parse_info(
+        &fixtures::WKT_PROJCS_NAD83.replace("Lambert_Conformal_Conic_2SP", "Unknown_Projection"),
+    )
+    .unwrap();
+    assert_eq!(info.crs_type, CrsKind::Projected);
+    assert_eq!(info.proj_string, None);
+
+    assert!(crate::wkt_parse_info(r#"UNIT["metre",1]"#).is_err());
+}
+
+#[test]
+fn build_projcs_missing_method() {
+    setup();
+    // Return the innermost error message
+    let error = |wkt: &str| {
+        let err = Builder::new().parse(wkt).unwrap_err();
+        err.root_cause().to_string()
+    };
+
+    const GEOGCS: &str = concat!(
+        r#"GEOGCS["WGS 84",DATUM["WGS_1984",SPHEROID["WGS 84",6378137,298.257223563]],"#,
+        r#"PRIMEM["Greenwich",0],UNIT["degree",0.0174532925199433]]"#,
+    );
+
+    // No projection block at all
+    assert_eq!(
+        error(&format!(r#"PROJCS["Test",{GEOGCS},UNIT["metre",1]]"#)),
+        "WKT error: missing PROJECTION/CONVERSION node"
+    );
+
+    // WKT1 parameters without PROJECTION
+    assert_eq!(
+        error(&format!(
+            r#"PROJCS["Test",{GEOGCS},PARAMETER["central_meridian",3],UNIT["metre",1]]"#
+        )),
+        "WKT error: projection has no METHOD"
+    );
+
+    // WKT2 CONVERSION without METHOD
+    assert_eq!(
+        error(&format!(
+            r#"PROJCRS["Test",{GEOGCS},CONVERSION["Test",PARAMETER["Longitude of natural origin",3]]]"#
+        )),
+        "WKT error: projection has no METHOD"
+    );
+}
+
+#[test]
+fn convert_wkt2_base_geogcrs_units() {
+    setup();
+    use crate::projstr_parser::ProjString;
+
+    let projstr = crate::wkt_to_projstring(fixtures::WKT_PROJCRS_NAD83_MASSACHUSETTS_FTUS).unwrap();
+    let ps = ProjString::parse(&projstr).unwrap();
+    let get = |key: &str| ps.get_number(key).unwrap().unwrap();
+    assert!((get("lat_1") - 42.6833333333333).abs() < 1e-9);
+    assert!((get("lon_0") + 71.5).abs() < 1e-9);
+    assert!((get("x_0") - 200000.0).abs() < 1e-3);
+    assert!((get("y_0") - 750000.0).abs() < 1e-3);
+    assert_eq!(get("to_meter"), 0.304800609601219);
+
+    // Parameters without units use the base geographic crs and
+    // the coordinate system units
+    let wkt = fixtures::WKT_PROJCRS_NAD83_MASSACHUSETTS_FTUS
+        .replace(r#",ANGLEUNIT["degree",0.0174532925199433],ID"#, ",ID")
+        .replace(r#",LENGTHUNIT["US survey foot",0.304800609601219],"#, ",");
+    assert!(!wkt.contains(r#"656166.667,LENGTHUNIT"#));
+    assert_eq!(crate::wkt_to_projstring(&wkt).unwrap(), projstr);
+
+    // WKT2 geographic crs with unit defined in axes
+    let Node::GEOGCRS(cs) = Builder::new()
+        .parse(concat!(
+            r#"GEOGCRS["NTF (Paris)",DATUM["Nouvelle Triangulation Francaise (Paris)","#,
+            r#"ELLIPSOID["Clarke 1880 (IGN)",6378249.2,293.466021293627,LENGTHUNIT["metre",1]]],"#,
+            r#"PRIMEM["Paris",2.5969213,ANGLEUNIT["grad",0.0157079632679489]],"#,
+            r#"CS[ellipsoidal,2],AXIS["geodetic latitude (Lat)",north,ORDER[1],"#,
+            r#"ANGLEUNIT["grad",0.0157079632679489]],AXIS["geodetic longitude (Lon)",east,"#,
+            r#"ORDER[2],ANGLEUNIT["grad",0.0157079632679489]],ID["EPSG",4807]]"#,
+        ))
+        .unwrap()
+    else {
+        panic!("Expecting GEOGCRS");
+    };
+    let unit = cs.unit.unwrap();
+    assert_eq!(unit.name, "grad");
+    assert_eq!(unit.unit_type, UnitType::Angular);
+}
+
+#[test]
+fn build_error_source_keywords() {
+    setup();
+    // Return the innermost error message
+    let error = |wkt: &str| {
+        let err = Builder::new().parse(wkt).unwrap_err();
+        err.root_cause().to_string()
+    };
+
+    assert_eq!(
+        error(r#"ELLIPSOID["GRS 1980",6378137]"#),
+        "WKT error: Invalid ELLIPSOID inverse flattening"
+    );
+    assert_eq!(
+        error(r#"SPHEROID["GRS 1980",6378137]"#),
+        "WKT error: Invalid SPHEROID inverse flattening"
+    );
+    assert_eq!(
+        error(r#"PRIMEMERIDIAN["Greenwich"]"#),
+        "WKT error: Missing PRIMEMERIDIAN longitude"
+    );
+    assert_eq!(error(r#"ID["EPSG"]"#), "WKT error: Missing ID code");
+    assert_eq!(
+        error(r#"GEODETICDATUM["WGS 84"]"#),
+        "WKT error: Missing ellipsoid for GEODETICDATUM"
+    );
+    assert_eq!(
+        error(r#"COMPD_CS["Test",VERT_CS["Test",UNIT["metre",1]]]"#),
+        "WKT error: Missing horizontal crs for COMPD_CS"
+    );
+
+    // Enclosing nodes keep their spelling
+    assert_eq!(
+        Builder::new()
+            .parse(r#"GEODETICCRS["WGS 84",TRF["WGS 84",ELLIPSOID["WGS 84"]]]"#)
+            .unwrap_err()
+            .to_string(),
+        "WKT error: Invalid GEODETICCRS"
+    );
+}
+
+#[test]
+fn build_coordinate_system() {
+    setup();
+    let Node::PROJCRS(cs) = Builder::new()
+        .parse(fixtures::WKT_PROJCRS_NAD83_MASSACHUSETTS_FTUS)
+        .unwrap()
+    else {
+        panic!("Expecting PROJCRS");
+    };
+    assert_eq!(
+        cs.cs,
+        Some(CoordinateSystem {
+            cs_type: CsType::Cartesian,
+            dimension: 2,
+        })
+    );
+    assert!(!cs.is_3d());
+    // No CS in WKT2 base geographic crs
+    assert_eq!(cs.geogcs.cs, None);
+
+    let Node::GEOGCRS(cs) = Builder::new()
+        .parse(concat!(
+            r#"GEOGCRS["WGS 84",DATUM["World Geodetic System 1984","#,
+            r#"ELLIPSOID["WGS 84",6378137,298.257223563,LENGTHUNIT["metre",1]]],"#,
+            r#"PRIMEM["Greenwich",0,ANGLEUNIT["degree",0.0174532925199433]],"#,
+            r#"CS[ellipsoidal,3],"#,
+            r#"AXIS["geodetic latitude (Lat)",north,ORDER[1],ANGLEUNIT["degree",0.0174532925199433]],"#,
+            r#"AXIS["geodetic longitude (Lon)",east,ORDER[2],ANGLEUNIT["degree",0.0174532925199433]],"#,
+            r#"AXIS["ellipsoidal height (h)",up,ORDER[3],LENGTHUNIT["metre",1]],ID["EPSG",4979]]"#,
+        ))
+        .unwrap()
+    else {
+        panic!("Expecting GEOGCRS");
+    };
+    assert_eq!(
+        cs.cs.as_ref().map(|c| &c.cs_type),
+        Some(&CsType::Ellipsoidal)
+    );
+    assert!(cs.is_3d());
+    assert_eq!(cs.unit.as_ref().map(|u| u.name), Some("degree"));
+
+    let Node::GEOCCRS(cs) = Builder::new()
+        .parse(fixtures::WKT_GEODCRS_WGS84_GEOCENTRIC)
+        .unwrap()
+    else {
+        panic!("Expecting GEOCCRS");
+    };
+    assert!(cs.is_3d());
+
+    // WKT1 has no coordinate system node
+    let Node::PROJCRS(cs) = Builder::new().parse(fixtures::WKT_PROJCS_NAD83).unwrap() else {
+        panic!("Expecting PROJCRS");
+    };
+    assert_eq!(cs.cs, None);
+    assert!(!cs.is_3d());
+
+    assert!(Builder::new().parse("CS[vertical]").is_err());
+}
+
+#[test]
+fn node_try_from_str() {
+    setup();
+    let node = Node::try_from(fixtures::WKT_PROJCS_NAD83).unwrap();
+    assert_eq!(
+        node,
+        Builder::new().parse(fixtures::WKT_PROJCS_NAD83).unwrap()
+    );
+
+    let wkt = String::from(fixtures::WKT_GEOGCS_WGS84);
+    let node: Node = (&wkt).try_into().unwrap();
+    assert_eq!(node.crs_kind(), Some(CrsKind::Geographic));
+
+    let result: Result<Node, crate::Error> = "GEOGCS(".try_into();
+    assert!(matches!(result, Err(crate::Error::Parse)));
+}
+
+#[test]
+fn convert_trinidad_cassini_soldner() {
+    setup();
+    use crate::projstr_parser::ProjString;
+
+    let projstr = crate::wkt_to_projstring(fixtures::WKT_PROJCS_TRINIDAD_GRID).unwrap();
+    assert!(projstr.starts_with("+proj=cass "), "{projstr}");
+    assert!(is_valid_projstring(&projstr));
+
+    let ps = ProjString::parse(&projstr).unwrap();
+    let get = |key: &str| ps.get_number(key).unwrap().unwrap();
+    assert!((get("lat_0") - 10.4416666666667).abs() < 1e-12);
+    assert!((get("lon_0") + 61.3333333333333).abs() < 1e-12);
+    // False origin in Clarke's links converted to metres
+    assert!((get("x_0") - 86501.46).abs() < 1e-2);
+    assert!((get("y_0") - 65379.01).abs() < 1e-2);
+    assert_eq!(get("to_meter"), 0.201166195164);
+    assert_eq!(ps.get("a"), Some("6378293.645208759"));
+    assert_eq!(ps.get("rf"), Some("294.2606763692654"));
+    assert_eq!(ps.get("towgs84"), Some("-61.702,284.488,472.052,0,0,0,0"));
+
+    let (_, report) =
+        crate::wkt_to_projstring_with_report(fixtures::WKT_PROJCS_TRINIDAD_GRID).unwrap();
+    assert_eq!(report.method_match, Some(MatchKind::Name));
+    assert!(report.dropped_parameters.is_empty());
+
+    // Unknown projection method
+    let wkt = fixtures::WKT_PROJCS_TRINIDAD_GRID.replace("Cassini_Soldner", "Cassini_Unknown");
+    assert!(matches!(
+        crate::wkt_to_projstring(&wkt),
+        Err(crate::Error::Wkt(msg)) if msg.starts_with("No projection mapping found")
+    ));
+
+    // Missing projection method
+    let wkt = fixtures::WKT_PROJCS_TRINIDAD_GRID.replace(r#"PROJECTION["Cassini_Soldner"],"#, "");
+    assert!(matches!(
+        crate::wkt_to_projstring(&wkt),
+        Err(crate::Error::Wkt(msg)) if msg == "projection has no METHOD"
+    ));
+}
+
+#[test]
+fn convert_pseudo_mercator_sphere() {
+    setup();
+    assert_eq!(
+        crate::wkt_to_projstring(fixtures::WKT_PROJCS_PSEUDO_MERCATOR).unwrap(),
+        concat!(
+            "+proj=webmerc +lat_0=0 +lon_0=0 +x_0=0 +y_0=0 +units=m",
+            " +a=6378137 +b=6378137 +towgs84=0,0,0,0,0,0,0",
+        )
+    );
+
+    // Sphere defined with a non metre unit
+    let projstr = crate::wkt_to_projstring(concat!(
+        r#"GEOGCRS["Sphere",DATUM["Sphere",ELLIPSOID["Sphere",6378.137,0,"#,
+        r#"LENGTHUNIT["kilometre",1000]]],PRIMEM["Greenwich",0,"#,
+        r#"ANGLEUNIT["degree",0.0174532925199433]],CS[ellipsoidal,2],"#,
+        r#"AXIS["latitude",north],AXIS["longitude",east],"#,
+        r#"ANGLEUNIT["degree",0.0174532925199433]]"#,
+    ))
+    .unwrap();
+    assert_eq!(
+        projstr,
+        "+proj=longlat +a=6378137 +b=6378137 +towgs84=0,0,0,0,0,0,0"
+    );
+}
+
+#[test]
+fn convert_derived_projcrs() {
+    setup();
+    let base = fixtures::WKT_PROJCRS_NAD83_MASSACHUSETTS_FTUS;
+    let derived = |base: &str| {
+        format!(
+            concat!(
+                r#"DERIVEDPROJCRS["Derived Massachusetts",{},"#,
+                r#"DERIVINGCONVERSION["Affine",METHOD["Affine parametric transformation","#,
+                r#"ID["EPSG",9624]]],CS[Cartesian,2],AXIS["(E)",east],AXIS["(N)",north],"#,
+                r#"LENGTHUNIT["US survey foot",0.304800609601219]]"#,
+            ),
+            base
+        )
+    };
+
+    let wkt = derived(base);
+    let node = Builder::new().parse(&wkt).unwrap();
+    assert_eq!(node.crs_name().as_deref(), Some("Derived Massachusetts"));
+    assert_eq!(node.crs_kind(), Some(CrsKind::Projected));
+    assert_eq!(
+        node.datum().map(|d| d.name),
+        Some("North American Datum 1983")
+    );
+    let Node::DERIVEDPROJCRS(crs) = &node else {
+        panic!("Expecting DERIVEDPROJCRS");
+    };
+    assert!(matches!(*crs.base, Node::PROJCRS(_)));
+
+    // The base projected crs is formatted
+    let expected = crate::wkt_to_projstring(base).unwrap();
+    let (projstr, diagnostics) = crate::wkt_to_projstring_with_diagnostics(&wkt).unwrap();
+    assert_eq!(projstr, expected);
+    assert_eq!(diagnostics.warnings().len(), 1);
+
+    // WKT2 2019 BASEPROJCRS keyword
+    let wkt = derived(&base.replacen("PROJCRS", "BASEPROJCRS", 1));
+    assert_eq!(crate::wkt_to_projstring(&wkt).unwrap(), expected);
+
+    // Missing base crs
+    let wkt = derived(r#"ID["EPSG",1]"#);
+    assert!(Builder::new().parse(&wkt).is_err());
+}
+
+#[test]
+fn convert_oblique_mercator_false_origin() {
+    setup();
+    // Variant A: false easting and northing at the natural origin
+    assert_eq!(
+        crate::wkt_to_projstring(fixtures::WKT_PROJCS_MICHIGAN_OBLIQUE_MERCATOR).unwrap(),
+        concat!(
+            "+proj=omerc +lat_0=45.3091666666667 +lonc=-86 +alpha=337.25556",
+            " +gamma=337.25556 +k=0.9996 +x_0=2546731.496 +y_0=-4354009.816",
+            " +units=m +a=6378137 +rf=298.257222101 +towgs84=0,0,0,0,0,0,0 +no_uoff",
+        )
+    );
+
+    // Variant B: easting and northing at the projection centre
+    let (projstr, report) =
+        crate::wkt_to_projstring_with_report(fixtures::WKT_PROJCRS_ALASKA_ZONE_1).unwrap();
+    assert_eq!(
+        projstr,
+        concat!(
+            "+proj=omerc +lat_0=57 +lonc=-133.666666666667 +alpha=323.130102361111",
+            " +gamma=323.130102361111 +k=0.9999 +x_0=5000000 +y_0=-5000000",
+            " +units=m +a=6378137 +rf=298.257222101 +towgs84=0,0,0,0,0,0,0",
+        )
+    );
+    assert_eq!(report.method_match, Some(MatchKind::Epsg));
+    assert!(report.dropped_parameters.is_empty());
+
+    // Natural origin false easting is not a variant B parameter
+    let wkt = fixtures::WKT_PROJCRS_ALASKA_ZONE_1
+        .replace("Easting at projection centre", "False easting")
+        .replace("8816", "8806");
+    let (projstr, report) = crate::wkt_to_projstring_with_report(&wkt).unwrap();
+    assert!(!projstr.contains("+x_0="), "{projstr}");
+    assert_eq!(report.dropped_parameters.len(), 1);
+}
+
+#[test]
+fn parse_wkt_embedded_in_gml() {
+    setup();
+    use crate::parser::{parse_fragment, parse_next_wkt_object};
+
+    let gml = format!(
+        "<gml:ProjectedCRS><gml:wkt>{}</gml:wkt></gml:ProjectedCRS>",
+        fixtures::WKT_PROJCS_NAD83
+    );
+    let start = gml.find("<gml:wkt>").unwrap() + "<gml:wkt>".len();
+
+    let node = parse_fragment(&gml[start..], &Builder::new()).unwrap();
+    assert_eq!(
+        node.crs_name().as_deref(),
+        Some("NAD83 / Massachusetts Mainland")
+    );
+
+    let (wkt, rest) = parse_next_wkt_object(&gml[start..]).unwrap();
+    assert_eq!(wkt, fixtures::WKT_PROJCS_NAD83);
+    assert_eq!(rest, "</gml:wkt></gml:ProjectedCRS>");
+}
+
+#[test]
+fn convert_wkt2_2019_utm32n() {
+    setup();
+    let node = Builder::new()
+        .parse(fixtures::WKT2_2019_PROJCRS_UTM32N)
+        .unwrap();
+
+    assert_eq!(node.crs_name().as_deref(), Some("WGS 84 / UTM zone 32N"));
+    assert_eq!(node.crs_kind(), Some(CrsKind::Projected));
+    assert_eq!(
+        node.authority().map(|auth| (auth.name, auth.code)),
+        Some(("EPSG", "32632"))
+    );
+    assert_eq!(node.bounding_box(), Some([0.0, 6.0, 84.0, 12.0]));
+    // The datum ensemble is used as datum
+    assert_eq!(
+        node.datum().map(|d| d.name),
+        Some("World Geodetic System 1984 ensemble")
+    );
+    assert_eq!(
+        node.remark().as_deref(),
+        Some(r#"Projected "UTM" zone for the WGS 84 ensemble."#)
+    );
+
+    let mut buf = String::new();
+    crate::Formatter::new_string(&mut buf)
+        .format(&node)
+        .unwrap();
+    assert_eq!(
+        buf,
+        concat!(
+            "+proj=tmerc +lat_0=0 +lon_0=9 +k=0.9996 +x_0=500000 +y_0=0",
+            " +units=m +a=6378137 +rf=298.257223563 +towgs84=0,0,0,0,0,0,0",
+        )
+    );
+    assert_eq!(
+        crate::wkt_extract_projcs_authority(fixtures::WKT2_2019_PROJCRS_UTM32N).unwrap(),
+        Some(("EPSG", "32632"))
+    );
+}
+
+#[test]
+fn crs_epsg_code() {
+    setup();
+    let Node::PROJCRS(cs) = Builder::new().parse(fixtures::WKT_PROJCS_NAD83).unwrap() else {
+        panic!("Expecting PROJCRS");
+    };
+    assert_eq!(cs.epsg_code(), Some("26986"));
+    assert_eq!(cs.geogcs.epsg_code(), Some("4269"));
+
+    let Node::GEOGCRS(cs) = Builder::new().parse(fixtures::WKT_GEOGCS_WGS84).unwrap() else {
+        panic!("Expecting GEOGCRS");
+    };
+    assert_eq!(cs.epsg_code(), Some("4326"));
+
+    assert_eq!(
+        crate::wkt_epsg_code(fixtures::WKT_PROJCS_NAD83).unwrap(),
+        Some("26986")
+    );
+    assert_eq!(
+        crate::wkt_epsg_code(fixtures::WKT_GEOGCS_WGS84).unwrap(),
+        Some("4326")
+    );
+    assert_eq!(
+        crate::wkt_epsg_code(fixtures::WKT2_2019_PROJCRS_UTM32N).unwrap(),
+        Some("32632")
+    );
+
+    // Non EPSG authority
+    let wkt = fixtures::WKT_PROJCS_NAD83
+        .replace(r#"AUTHORITY["EPSG","26986"]"#, r#"AUTHORITY["IGNF","X"]"#);
+    assert_eq!(crate::wkt_epsg_code(&wkt).unwrap(), None);
+}
+
+#[test]
+fn node_parameters() {
+    setup();
+    let node = Builder::new().parse(fixtures::WKT_PROJCS_NAD83).unwrap();
+    let params: Vec<_> = node
+        .parameters()
+        .unwrap()
+        .iter()
+        .map(|p| (p.name, p.value))
+        .collect();
+    assert_eq!(
+        params,
+        [
+            ("standard_parallel_1", "42.68333333333333"),
+            ("standard_parallel_2", "41.71666666666667"),
+            ("latitude_of_origin", "-41"),
+            ("central_meridian", "-71.5"),
+            ("false_easting", "200000"),
+            ("false_northing", "750000"),
+        ]
+    );
+
+    // WKT2 parameters carry their unit
+    let node = Builder::new()
+        .parse(fixtures::WKT2_2019_PROJCRS_UTM32N)
+        .unwrap();
+    let params = node.parameters().unwrap();
+    assert_eq!(params.len(), 5);
+    assert!(params.iter().all(|p| p.unit.is_some()));
+
+    let node = Builder::new().parse(fixtures::WKT_GEOGCS_WGS84).unwrap();
+    assert!(node.parameters().is_none());
+}
+
+#[test]
+fn parse_wkt_reader() {
+    setup();
+    let input = format!(
+        "{}\n{}\nGEOGCS[\"Broken\"]\n{}\n",
+        fixtures::WKT_PROJCS_NAD83,
+        fixtures::WKT_GEOGCS_WGS84,
+        fixtures::WKT2_2019_PROJCRS_UTM32N,
+    );
+    let items: Vec<_> = Builder::parse_reader(input.as_bytes()).collect();
+    assert_eq!(items.len(), 4);
+
+    let crs = items[0].as_ref().unwrap();
+    assert_eq!(crs.as_str(), fixtures::WKT_PROJCS_NAD83);
+    assert_eq!(
+        crs.node().unwrap().crs_name().as_deref(),
+        Some("NAD83 / Massachusetts Mainland")
+    );
+    assert_eq!(
+        items[1].as_ref().unwrap().node().unwrap().crs_kind(),
+        Some(CrsKind::Geographic)
+    );
+    // Invalid objects do not stop the iteration
+    assert!(items[2].is_err());
+    assert_eq!(
+        items[3]
+            .as_ref()
+            .unwrap()
+            .node()
+            .unwrap()
+            .authority()
+            .map(|a| a.code),
+        Some("32632")
+    );
+
+    // Unterminated object
+    let mut items = Builder::parse_reader(&fixtures::WKT_GEOGCS_WGS84.as_bytes()[..40]);
+    assert!(matches!(items.next(), Some(Err(crate::Error::Parse))));
+    assert!(items.next().is_none());
+
+    let owned = crate::OwnedNode::try_from(String::from(fixtures::WKT_GEOGCS_WGS84)).unwrap();
+    assert_eq!(owned.into_string(), fixtures::WKT_GEOGCS_WGS84);
+}
+
+#[test]
+fn build_lenient_geographic_unit() {
+    setup();
+    let wkt = fixtures::WKT_PROJCS_NAD83.replace(
+        r#"UNIT["degree",0.01745329251994328,AUTHORITY["EPSG","9122"]]"#,
+        r#"LENGTHUNIT["metre",1]"#,
+    );
+    assert_ne!(wkt, fixtures::WKT_PROJCS_NAD83);
+
+    // Strict by default
+    let err = Builder::new().parse(&wkt).unwrap_err();
+    assert_eq!(
+        err.root_cause().to_string(),
+        "WKT error: Expecting angular unit for geodetic crs"
+    );
+
+    let builder = Builder::new().lenient_units(true);
+    let (node, diagnostics) = builder.parse_with_diagnostics(&wkt).unwrap();
+    let Node::PROJCRS(cs) = &node else {
+        panic!("Expecting PROJCRS");
+    };
+    assert!(cs.geogcs.unit.is_none());
+    assert_eq!(diagnostics.warnings().len(), 1);
+
+    let mut buf = String::new();
+    crate::Formatter::new_string(&mut buf)
+        .format(&node)
+        .unwrap();
+    assert_eq!(
+        buf,
+        crate::wkt_to_projstring(fixtures::WKT_PROJCS_NAD83).unwrap()
+    );
+
+    // Untyped WKT1 unit
+    let wkt = fixtures::WKT_PROJCS_NAD83.replace(
+        r#"UNIT["degree",0.01745329251994328,AUTHORITY["EPSG","9122"]]"#,
+        r#"UNIT["metre",1,AUTHORITY["EPSG","9001"]]"#,
+    );
+    let (_, diagnostics) = builder.parse_with_diagnostics(&wkt).unwrap();
+    assert_eq!(diagnostics.warnings().len(), 1);
+
+    let (_, diagnostics) = builder
+        .parse_with_diagnostics(fixtures::WKT_PROJCS_NAD83)
+        .unwrap();
+    assert!(diagnostics.is_empty());
+}
+
+#[test]
+fn build_depth_warnings() {
+    setup();
+    use crate::builder::depth_warning;
+
+    assert_eq!(depth_warning("PROJCS", 0), None);
+    assert_eq!(depth_warning("DATUM", 1), None);
+    assert_eq!(
+        depth_warning("DATUM", 0).as_deref(),
+        Some("Unexpected top-level keyword DATUM")
+    );
+    assert_eq!(depth_warning("AUTHORITY", 5), None);
+
+    // Collect the depth of the nodes of a deeply nested WKT
+    struct Depths(std::cell::RefCell<Vec<(String, usize)>>);
+
+    impl<'a> crate::parser::Processor<'a> for Depths {
+        type Err = crate::Error;
+        type Output = ();
+
+        fn process<I>(&self, key: &'a str, depth: usize, attrs: I) -> Result<(), crate::Error>
+        where
+            I: Iterator<Item = crate::parser::Attribute<'a, ()>>,
+        {
+            attrs.for_each(drop);
+            self.0.borrow_mut().push((key.into(), depth));
+            Ok(())
+        }
+    }
+
+    let wkt = format!(
+        r#"COMPD_CS["NAD83 + height",{},VERT_CS["height",VERT_DATUM["Ordnance Datum",2005]]]"#,
+        fixtures::WKT_PROJCS_NAD83
+    );
+    let depths = Depths(Default::default());
+    crate::parser::parse(&wkt, &depths).unwrap();
+    let warnings: Vec<_> = depths
+        .0
+        .borrow()
+        .iter()
+        .filter_map(|(key, depth)| depth_warning(key, *depth))
+        .collect();
+    assert!(warnings.is_empty(), "{warnings:?}");
+    // Ellipsoid authority of a compound crs
+    assert!(depths.0.borrow().contains(&("AUTHORITY".into(), 5)));
+
+    // Warnings do not prevent parsing
+    assert!(Builder::new().parse(&wkt).is_ok());
+}
+
+#[test]
+fn convert_krovak_orientation() {
+    setup();
+    let (projstr, report) =
+        crate::wkt_to_projstring_with_report(fixtures::WKT_PROJCRS_SJTSK_KROVAK_EAST_NORTH)
+            .unwrap();
+    assert_eq!(
+        projstr,
+        concat!(
+            "+proj=krovak +lat_0=49.5 +lon_0=24.8333333333333 +alpha=30.2881397527778",
+            " +k=0.9999 +x_0=0 +y_0=0 +units=m +a=6377397.155 +rf=299.1528128",
+            " +towgs84=0,0,0,0,0,0,0",
+        )
+    );
+    assert_eq!(report.method_match, Some(MatchKind::Epsg));
+    assert!(report.diagnostics.is_empty(), "{:?}", report.diagnostics);
+
+    // Southing and westing
+    let projstr = crate::wkt_to_projstring(fixtures::WKT_PROJCS_SJTSK_KROVAK).unwrap();
+    assert_eq!(
+        projstr,
+        concat!(
+            "+proj=krovak +lat_0=49.5 +lon_0=24.8333333333333 +alpha=30.2881397527778",
+            " +k=0.9999 +x_0=0 +y_0=0 +units=m +a=6377397.155 +rf=299.1528128",
+            " +towgs84=0,0,0,0,0,0,0 +axis=swu",
+        )
+    );
+
+    // WKT1 Krovak with east/north axes is north orientated
+    let wkt = fixtures::WKT_PROJCS_SJTSK_KROVAK.replace(
+        r#"AXIS["X",SOUTH],AXIS["Y",WEST]"#,
+        r#"AXIS["X",EAST],AXIS["Y",NORTH]"#,
+    );
+    assert_eq!(
+        crate::wkt_to_projstring(&wkt).unwrap(),
+        crate::wkt_to_projstring(fixtures::WKT_PROJCRS_SJTSK_KROVAK_EAST_NORTH).unwrap()
+    );
+
+    // WKT1 axes default to east/north
+    let (projstr, report) =
+        crate::wkt_to_projstring_with_report(fixtures::WKT_PROJCS_SJTSK_KROVAK_NO_AXIS).unwrap();
+    assert_eq!(
+        projstr,
+        crate::wkt_to_projstring(fixtures::WKT_PROJCRS_SJTSK_KROVAK_EAST_NORTH).unwrap()
+    );
+    assert_eq!(
+        report.diagnostics.warnings(),
+        ["No axes defined for 'S-JTSK / Krovak', assuming east/north axes"]
+    );
+}
+
+#[test]
+fn convert_lcc_2sp_michigan() {
+    setup();
+    let (projstr, report) =
+        crate::wkt_to_projstring_with_report(fixtures::WKT_PROJCRS_MICHIGAN_NORTH).unwrap();
+    assert_eq!(
+        projstr,
+        concat!(
+            "+proj=lcc +lat_0=44.7833333333333 +lon_0=-87 +lat_1=45.4833333333333",
+            " +lat_2=47.0833333333333 +x_0=609601.219202438 +y_0=0 +k_0=1.0000382",
+            " +to_meter=0.304800609601219 +a=6378206.4 +rf=294.978698213898",
+            " +nadgrids=@conus,@alaska,@ntv2_0.gsb,@ntv1_can.dat",
+        )
+    );
+    assert_eq!(report.method_match, Some(MatchKind::Epsg));
+    assert!(report.diagnostics.is_empty(), "{:?}", report.diagnostics);
+
+    // Ellipsoid scaling factor in parts per million
+    let wkt = fixtures::WKT_PROJCRS_MICHIGAN_NORTH.replace(
+        r#"1.0000382,SCALEUNIT["unity",1]"#,
+        r#"1000038.2,SCALEUNIT["parts per million",1E-06]"#,
+    );
+    let projstr = crate::wkt_to_projstring(&wkt).unwrap();
+    let k_0 = projstr
+        .split(' ')
+        .find_map(|t| t.strip_prefix("+k_0="))
+        .map(|v| crate::builder::parse_number(v).unwrap());
+    approx::assert_abs_diff_eq!(k_0.unwrap(), 1.0000382, epsilon = 1e-12);
+
+    // Invalid unit for a scale parameter
+    let wkt = fixtures::WKT_PROJCRS_MICHIGAN_NORTH.replace(
+        r#"1.0000382,SCALEUNIT["unity",1]"#,
+        r#"1.0000382,LENGTHUNIT["metre",1]"#,
+    );
+    let (projstr, report) = crate::wkt_to_projstring_with_report(&wkt).unwrap();
+    assert!(projstr.contains(" +k_0=1.0000382 "), "{projstr}");
+    assert_eq!(
+        report.diagnostics.warnings(),
+        ["Unexpected unit 'metre' for scale parameter 'Ellipsoid scaling factor' was ignored"]
+    );
+}
+
+#[test]
+fn build_duplicate_parameters_last_wins() {
+    setup();
+    // WKT1: parameters at the crs level
+    let wkt = fixtures::WKT_PROJCS_NAD83.replace(
+        r#"PARAMETER["false_easting",200000]"#,
+        r#"PARAMETER["false_easting",100],PARAMETER["false_easting",200000]"#,
+    );
+    assert_ne!(wkt, fixtures::WKT_PROJCS_NAD83);
+
+    let builder = Builder::new();
+    let (node, diagnostics) = builder.parse_with_diagnostics(&wkt).unwrap();
+    let Node::PROJCRS(cs) = &node else {
+        panic!("Expecting PROJCRS");
+    };
+    let values: Vec<_> = cs
+        .projection
+        .parameters
+        .iter()
+        .filter(|p| p.name == "false_easting")
+        .map(|p| p.value)
+        .collect();
+    assert_eq!(values, ["200000"]);
+    assert_eq!(
+        diagnostics.warnings(),
+        ["Duplicate parameter 'false_easting', value 100 was overridden"]
+    );
+    assert_eq!(
+        crate::wkt_to_projstring(&wkt).unwrap(),
+        crate::wkt_to_projstring(fixtures::WKT_PROJCS_NAD83).unwrap()
+    );
+
+    // Builder warnings are reported by the conversion
+    let (_, report) = crate::wkt_to_projstring_with_report(&wkt).unwrap();
+    assert_eq!(
+        report.diagnostics.warnings(),
+        ["Duplicate parameter 'false_easting', value 100 was overridden"]
+    );
+
+    // Builder warnings come before the formatter warnings
+    let wkt = wkt.replace(
+        r#"PARAMETER["central_meridian","#,
+        r#"PARAMETER["foo",1],PARAMETER["central_meridian",3],PARAMETER["central_meridian","#,
+    );
+    let (_, diagnostics) = crate::wkt_to_projstring_with_diagnostics(&wkt).unwrap();
+    assert_eq!(
+        diagnostics.warnings(),
+        [
+            "Duplicate parameter 'central_meridian', value 3 was overridden",
+            "Duplicate parameter 'false_easting', value 100 was overridden",
+            "Parameter 'foo' has no proj mapping and was ignored",
+        ]
+    );
+
+    // WKT2: parameters of the conversion
+    let wkt = fixtures::WKT2_2019_PROJCRS_UTM32N.replace(
+        r#"PARAMETER["False easting",500000"#,
+        r#"PARAMETER["False easting",100,LENGTHUNIT["metre",1]],PARAMETER["False easting",500000"#,
+    );
+    assert_ne!(wkt, fixtures::WKT2_2019_PROJCRS_UTM32N);
+    let (_, diagnostics) = builder.parse_with_diagnostics(&wkt).unwrap();
+    assert_eq!(diagnostics.warnings().len(), 1);
+    assert_eq!(
+        crate::wkt_to_projstring(&wkt).unwrap(),
+        crate::wkt_to_projstring(fixtures::WKT2_2019_PROJCRS_UTM32N).unwrap()
+    );
+}
+
+#[test]
+fn wkt_quick_type_check() {
+    setup();
+    assert!(crate::wkt_is_geographic(fixtures::WKT_GEOGCS_WGS84));
+    assert!(!crate::wkt_is_projected(fixtures::WKT_GEOGCS_WGS84));
+    assert!(crate::wkt_is_projected(fixtures::WKT_PROJCS_NAD83));
+    assert!(!crate::wkt_is_geographic(fixtures::WKT_PROJCS_NAD83));
+    assert!(crate::wkt_is_projected(fixtures::WKT2_2019_PROJCRS_UTM32N));
+    assert!(crate::wkt_is_geographic(r#"  BASEGEODCRS["NAD83"]"#));
+
+    // Consistent with the full parse
+    for wkt in [
+        fixtures::WKT_GEOGCS_WGS84,
+        fixtures::WKT_PROJCS_NAD83,
+        fixtures::WKT2_2019_PROJCRS_UTM32N,
+    ] {
+        let kind = Builder::new().parse(wkt).unwrap().crs_kind();
+        assert_eq!(
+            crate::wkt_is_geographic(wkt),
+            kind == Some(CrsKind::Geographic)
+        );
+        assert_eq!(
+            crate::wkt_is_projected(wkt),
+            kind == Some(CrsKind::Projected)
+        );
+    }
+
+    assert!(!crate::wkt_is_geographic(""));
+    assert!(!crate::wkt_is_projected("<gml:wkt>PROJCS[]"));
+    assert!(!crate::wkt_is_geographic(r#"GEOGCS_EXT["WGS 84"]"#));
+}
+
+#[test]
+fn escaped_quotes_round_trip() {
+    setup();
+    // Quoted strings are kept escaped in the tree, so they may be
+    // written back verbatim, accessors return the unescaped value
+    let wkt = fixtures::WKT_GEOGCS_WGS84.replacen(r#""WGS 84""#, r#""My ""Special"" CRS""#, 1);
+    assert_ne!(wkt, fixtures::WKT_GEOGCS_WGS84);
+    let node = Builder::new().parse(&wkt).unwrap();
+    let Node::GEOGCRS(cs) = &node else {
+        panic!("Expecting GEOGCRS");
+    };
+    assert_eq!(cs.name, r#"My ""Special"" CRS"#);
+    assert_eq!(node.crs_name().as_deref(), Some(r#"My "Special" CRS"#));
+
+    // Strings from proj parameters are escaped
+    let projstr = r#"+proj=longlat +ellps=GRS80 +nadgrids=my"grid".gsb"#;
+    let wkt = crate::projstring_to_wkt(projstr).unwrap();
+    assert!(wkt.contains(r#"NADGRIDS["my""grid"".gsb"]"#), "{wkt}");
+
+    // and unescaped back
+    let projstr = crate::wkt_to_projstring(&wkt).unwrap();
+    assert!(projstr.contains(r#" +nadgrids=my"grid".gsb"#), "{projstr}");
 }
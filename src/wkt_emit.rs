@@ -0,0 +1,446 @@
+//!
+//! Emit WKT1 or WKT2 from proj string
+//!
+//! Reverse conversion, limited to the projections
+//! defined in the method mapping table.
+//!
+use crate::builder::parse_number;
+use crate::ellipsoids::find_ellipsoid_by_name;
+use crate::errors::{Error, Result};
+use crate::methods::{method_mappings, MethodMapping};
+use crate::model::UnitType;
+use crate::prime_meridians::KNOWN_PRIME_MERIDIANS;
+use crate::projstr_parser::ProjString;
+
+use std::fmt::{self, Write as _};
+use std::io::Write;
+
+// Well known datums as `(proj name, WKT name, ellipsoid)`, WKT names
+// match the datum presets used for the reverse conversion
+const DATUMS: [(&str, &str, &str); 3] = [
+    ("WGS84", "WGS_1984", "WGS84"),
+    ("NAD83", "North_American_Datum_1983", "GRS80"),
+    ("NAD27", "North_American_Datum_1927", "clrk66"),
+];
+
+// Linear units as `(proj name, wkt name, factor)`
+const LINEAR_UNITS: [(&str, &str, &str); 3] = [
+    ("m", "metre", "1"),
+    ("ft", "foot", "0.3048"),
+    ("us-ft", "US survey foot", "0.304800609601219"),
+];
+
+// WKT2 units
+const DEGREE_WKT2: &str = r#"ANGLEUNIT["degree",0.0174532925199433]"#;
+const METRE_WKT2: &str = r#"LENGTHUNIT["metre",1]"#;
+const UNITY_WKT2: &str = r#"SCALEUNIT["unity",1]"#;
+
+// WKT2 target crs of TOWGS84 transformations
+const WGS84_WKT2: &str = concat!(
+    r#"GEOGCRS["WGS 84",DATUM["World Geodetic System 1984","#,
+    r#"ELLIPSOID["WGS 84",6378137,298.257223563,LENGTHUNIT["metre",1]]],"#,
+    r#"PRIMEM["Greenwich",0,ANGLEUNIT["degree",0.0174532925199433]],"#,
+    r#"CS[ellipsoidal,2],AXIS["latitude",north,ORDER[1],ANGLEUNIT["degree",0.0174532925199433]],"#,
+    r#"AXIS["longitude",east,ORDER[2],ANGLEUNIT["degree",0.0174532925199433]],ID["EPSG",4326]]"#,
+);
+
+// TOWGS84 parameters as `(WKT2 name, EPSG code)`
+#[rustfmt::skip]
+const TOWGS84_PARAMETERS: [(&str, &str); 7] = [
+    ("X-axis translation", "8605"),
+    ("Y-axis translation", "8606"),
+    ("Z-axis translation", "8607"),
+    ("X-axis rotation",    "8608"),
+    ("Y-axis rotation",    "8609"),
+    ("Z-axis rotation",    "8610"),
+    ("Scale difference",   "8611"),
+];
+
+/// Emit WKT1 or WKT2 to [`Write`] from a tokenized proj string
+pub struct WktEmitter<T: Write> {
+    w: T,
+}
+
+impl<T: Write> WktEmitter<T> {
+    /// Create a new emitter
+    pub fn new(w: T) -> Self {
+        Self { w }
+    }
+
+    /// Emit a WKT1 `GEOGCS` or `PROJCS` from proj string
+    pub fn emit(&mut self, ps: &ProjString) -> Result<()> {
+        match ps.get("proj") {
+            Some("longlat" | "latlong" | "lonlat") => self.add_geogcs(ps),
+            Some(proj) => self.add_projcs(proj, ps),
+            None => Err(Error::Wkt("Missing +proj parameter".into())),
+        }
+    }
+
+    /// Emit a WKT2 `GEOGCRS` or `PROJCRS` from proj string
+    ///
+    /// The crs is wrapped in a `BOUNDCRS` if a `+towgs84`
+    /// transformation is defined.
+    pub fn emit_wkt2(&mut self, ps: &ProjString) -> Result<()> {
+        let towgs84 = ps.get("towgs84");
+        if towgs84.is_some() {
+            write!(self.w, "BOUNDCRS[SOURCECRS[")?;
+        }
+        match ps.get("proj") {
+            Some("longlat" | "latlong" | "lonlat") => self.add_geogcrs(ps, true)?,
+            Some(proj) => self.add_projcrs(proj, ps)?,
+            None => return Err(Error::Wkt("Missing +proj parameter".into())),
+        }
+        if let Some(towgs84) = towgs84 {
+            write!(self.w, "],TARGETCRS[{WGS84_WKT2}],")?;
+            self.add_abridged_transformation(towgs84)?;
+            write!(self.w, "]")?;
+        }
+        Ok(())
+    }
+
+    fn add_geogcs(&mut self, ps: &ProjString) -> Result<()> {
+        write!(self.w, r#"GEOGCS["unknown","#)?;
+        self.add_datum(ps)?;
+        self.add_prime_meridian(ps)?;
+        write!(self.w, r#",UNIT["degree",0.0174532925199433]]"#)?;
+        Ok(())
+    }
+
+    fn add_datum(&mut self, ps: &ProjString) -> Result<()> {
+        let name = datum_name(ps)?;
+        let (a, rf) = ellipsoid(ps)?;
+        write!(self.w, r#"DATUM["{name}",SPHEROID["unknown",{a},{rf}]"#)?;
+        if let Some(towgs84) = ps.get("towgs84") {
+            if !matches!(towgs84.split(',').count(), 3 | 7) {
+                return Err(Error::Wkt(format!("Invalid +towgs84={towgs84}").into()));
+            }
+            for v in towgs84.split(',') {
+                parse_number(v)?;
+            }
+            write!(self.w, ",TOWGS84[{towgs84}]")?;
+        }
+        if let Some(nadgrids) = ps.get("nadgrids") {
+            write!(self.w, ",NADGRIDS[{}]", Quoted(nadgrids))?;
+        }
+        write!(self.w, "]")?;
+        Ok(())
+    }
+
+    fn add_prime_meridian(&mut self, ps: &ProjString) -> Result<()> {
+        match ps.get("pm") {
+            None => write!(self.w, r#",PRIMEM["Greenwich",0]"#)?,
+            Some(pm) => match KNOWN_PRIME_MERIDIANS
+                .iter()
+                .find(|(_, _, name)| *name == pm)
+            {
+                Some((name, lon, _)) => write!(self.w, r#",PRIMEM["{name}",{lon}]"#)?,
+                None => write!(self.w, r#",PRIMEM["unknown",{}]"#, parse_number(pm)?)?,
+            },
+        }
+        Ok(())
+    }
+
+    fn add_projcs(&mut self, proj: &str, ps: &ProjString) -> Result<()> {
+        let mapping = find_mapping(proj, ps, MethodMapping::wkt1_name)
+            .ok_or_else(|| Error::Wkt(format!("No WKT mapping for +proj={proj}").into()))?;
+
+        write!(self.w, r#"PROJCS["unknown","#)?;
+        self.add_geogcs(ps)?;
+        write!(self.w, r#",PROJECTION["{}"]"#, mapping.wkt1_name())?;
+
+        // Linear parameters are in metre in proj strings
+        // and in the projected unit in WKT1
+        let factor = linear_unit(ps)?.2;
+
+        let mut written: Vec<&str> = vec![];
+        for pm in mapping.parameters() {
+            if pm.proj_name.is_empty() || pm.wkt1_name.is_empty() || written.contains(&pm.wkt1_name)
+            {
+                continue;
+            }
+            match ps.get(pm.proj_name) {
+                Some(v) if pm.unit_type == UnitType::Linear && factor != 1.0 => write!(
+                    self.w,
+                    r#",PARAMETER["{}",{}]"#,
+                    pm.wkt1_name,
+                    parse_number(v)? / factor
+                )?,
+                Some(v) => {
+                    parse_number(v)?;
+                    write!(self.w, r#",PARAMETER["{}",{v}]"#, pm.wkt1_name)?;
+                }
+                None => {
+                    let default = if pm.unit_type == UnitType::Scale {
+                        "1"
+                    } else {
+                        "0"
+                    };
+                    write!(self.w, r#",PARAMETER["{}",{default}]"#, pm.wkt1_name)?;
+                }
+            }
+            written.push(pm.wkt1_name);
+        }
+
+        self.add_linear_unit(ps)?;
+        write!(self.w, "]")?;
+        Ok(())
+    }
+
+    fn add_linear_unit(&mut self, ps: &ProjString) -> Result<()> {
+        let (name, factor, _) = linear_unit(ps)?;
+        write!(self.w, r#",UNIT["{name}",{factor}]"#)?;
+        Ok(())
+    }
+
+    // WKT2 geographic crs, `BASEGEOGCRS` if not standalone
+    fn add_geogcrs(&mut self, ps: &ProjString, standalone: bool) -> Result<()> {
+        if ps.get("nadgrids").is_some() {
+            return Err(Error::Wkt(
+                "Grid based datum shift is not supported in WKT2".into(),
+            ));
+        }
+        let name = datum_name(ps)?;
+        let (a, rf) = ellipsoid(ps)?;
+        let keyword = if standalone { "GEOGCRS" } else { "BASEGEOGCRS" };
+        write!(
+            self.w,
+            r#"{keyword}["unknown",DATUM["{name}",ELLIPSOID["unknown",{a},{rf},{METRE_WKT2}]]"#
+        )?;
+        match ps.get("pm") {
+            None => write!(self.w, r#",PRIMEM["Greenwich",0,{DEGREE_WKT2}]"#)?,
+            Some(pm) => match KNOWN_PRIME_MERIDIANS
+                .iter()
+                .find(|(_, _, name)| *name == pm)
+            {
+                Some((name, lon, _)) => write!(self.w, r#",PRIMEM["{name}",{lon},{DEGREE_WKT2}]"#)?,
+                None => write!(
+                    self.w,
+                    r#",PRIMEM["unknown",{},{DEGREE_WKT2}]"#,
+                    parse_number(pm)?
+                )?,
+            },
+        }
+        if standalone {
+            write!(
+                self.w,
+                concat!(
+                    r#",CS[ellipsoidal,2],AXIS["longitude",east,ORDER[1],{deg}],"#,
+                    r#"AXIS["latitude",north,ORDER[2],{deg}]"#,
+                ),
+                deg = DEGREE_WKT2
+            )?;
+        }
+        write!(self.w, "]")?;
+        Ok(())
+    }
+
+    // WKT2 projected crs
+    fn add_projcrs(&mut self, proj: &str, ps: &ProjString) -> Result<()> {
+        let mapping = find_mapping(proj, ps, MethodMapping::wkt2_name)
+            .ok_or_else(|| Error::Wkt(format!("No WKT mapping for +proj={proj}").into()))?;
+
+        write!(self.w, r#"PROJCRS["unknown","#)?;
+        self.add_geogcrs(ps, false)?;
+        write!(
+            self.w,
+            r#",CONVERSION["unknown",METHOD["{}"{}]"#,
+            mapping.wkt2_name(),
+            epsg_id(mapping.epsg_code()),
+        )?;
+
+        let mut written: Vec<&str> = vec![];
+        for pm in mapping.parameters() {
+            if pm.proj_name.is_empty() || written.contains(&pm.wkt2_name) {
+                continue;
+            }
+            let unit = match pm.unit_type {
+                UnitType::Linear => METRE_WKT2,
+                UnitType::Angular => DEGREE_WKT2,
+                UnitType::Scale => UNITY_WKT2,
+                UnitType::Unknown => "",
+            };
+            let value = match ps.get(pm.proj_name) {
+                Some(v) => {
+                    parse_number(v)?;
+                    v
+                }
+                None if pm.unit_type == UnitType::Scale => "1",
+                None => "0",
+            };
+            write!(self.w, r#",PARAMETER["{}",{value}"#, pm.wkt2_name)?;
+            if !unit.is_empty() {
+                write!(self.w, ",{unit}")?;
+            }
+            write!(self.w, "{}]", epsg_id(pm.epsg_code))?;
+            written.push(pm.wkt2_name);
+        }
+
+        let (name, factor, _) = linear_unit(ps)?;
+        write!(
+            self.w,
+            concat!(
+                r#"],CS[Cartesian,2],AXIS["(E)",east,ORDER[1],LENGTHUNIT["{name}",{factor}]],"#,
+                r#"AXIS["(N)",north,ORDER[2],LENGTHUNIT["{name}",{factor}]]]"#,
+            ),
+            name = name,
+            factor = factor,
+        )?;
+        Ok(())
+    }
+
+    // WKT2 transformation from TOWGS84 parameters
+    fn add_abridged_transformation(&mut self, towgs84: &str) -> Result<()> {
+        let values = towgs84.split(',').collect::<Vec<_>>();
+        let method = match values.len() {
+            3 => r#"METHOD["Geocentric translations (geog2D domain)",ID["EPSG",9603]]"#,
+            7 => r#"METHOD["Position Vector transformation (geog2D domain)",ID["EPSG",9606]]"#,
+            _ => return Err(Error::Wkt(format!("Invalid +towgs84={towgs84}").into())),
+        };
+        write!(
+            self.w,
+            r#"ABRIDGEDTRANSFORMATION["Transformation to WGS84",{method}"#
+        )?;
+        for (i, (v, (name, code))) in values.iter().zip(TOWGS84_PARAMETERS).enumerate() {
+            // The scale difference of an abridged transformation
+            // is given as a scale factor, like PROJ does
+            let v = if i == 6 {
+                ppm_to_scale_factor(v)?
+            } else {
+                parse_number(v)?;
+                v.to_string()
+            };
+            write!(self.w, r#",PARAMETER["{name}",{v},ID["EPSG",{code}]]"#)?;
+        }
+        write!(self.w, "]")?;
+        Ok(())
+    }
+}
+
+// Return the scale factor of a scale difference in parts per
+// million, keeping the decimals of the difference
+fn ppm_to_scale_factor(ppm: &str) -> Result<String> {
+    let factor = 1.0 + parse_number(ppm)? * 1e-6;
+    if ppm.contains(['e', 'E']) {
+        return Ok(factor.to_string());
+    }
+    let decimals = ppm.split_once('.').map_or(0, |(_, d)| d.len()) + 6;
+    Ok(parse_number(&format!("{factor:.decimals$}"))?.to_string())
+}
+
+// WKT quoted string, embedded double quotes are escaped
+// by doubling them
+struct Quoted<'a>(&'a str);
+
+impl fmt::Display for Quoted<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_char('"')?;
+        for (i, part) in self.0.split('"').enumerate() {
+            if i > 0 {
+                f.write_str(r#""""#)?;
+            }
+            f.write_str(part)?;
+        }
+        f.write_char('"')
+    }
+}
+
+// Return the EPSG identifier node if the code is defined
+fn epsg_id(code: &str) -> String {
+    if code.is_empty() {
+        String::new()
+    } else {
+        format!(r#",ID["EPSG",{code}]"#)
+    }
+}
+
+// Return the linear unit name, its factor as a token
+// and its factor as a number
+fn linear_unit<'a>(ps: &ProjString<'a>) -> Result<(&'a str, &'a str, f64)> {
+    if let Some(to_meter) = ps.get("to_meter") {
+        Ok(("unknown", to_meter, parse_number(to_meter)?))
+    } else {
+        let units = ps.get("units").unwrap_or("m");
+        let (_, name, factor) = LINEAR_UNITS
+            .iter()
+            .find(|(u, _, _)| *u == units)
+            .ok_or_else(|| Error::Wkt(format!("Unsupported unit '{units}'").into()))?;
+        Ok((name, factor, parse_number(factor)?))
+    }
+}
+
+// Return the WKT datum name, datums which are not
+// known have no representable shift
+fn datum_name(ps: &ProjString) -> Result<&'static str> {
+    match ps.get("datum") {
+        Some(datum) => DATUMS
+            .iter()
+            .find(|(name, _, _)| *name == datum)
+            .map(|(_, wkt_name, _)| *wkt_name)
+            .ok_or_else(|| Error::Wkt(format!("Unsupported datum '{datum}'").into())),
+        None => Ok("unknown"),
+    }
+}
+
+// Return the semi-major axis and the inverse flattening
+fn ellipsoid(ps: &ProjString) -> Result<(String, String)> {
+    if let Some(a) = ps.get("a").or_else(|| ps.get("R")) {
+        let rf = if let Some(rf) = ps.get("rf") {
+            parse_number(rf)?
+        } else if let Some(b) = ps.get_number("b")? {
+            let a = parse_number(a)?;
+            if a == b {
+                0.
+            } else {
+                a / (a - b)
+            }
+        } else if let Some(f) = ps.get_number("f")? {
+            if f == 0. {
+                0.
+            } else {
+                1. / f
+            }
+        } else {
+            // Sphere
+            0.
+        };
+        Ok((parse_number(a)?.to_string(), rf.to_string()))
+    } else {
+        let ellps = ps
+            .get("ellps")
+            .or_else(|| {
+                let datum = ps.get("datum")?;
+                DATUMS
+                    .iter()
+                    .find(|(name, _, _)| *name == datum)
+                    .map(|(_, _, ellps)| *ellps)
+            })
+            .ok_or(Error::Wkt("Missing ellipsoid definition".into()))?;
+        find_ellipsoid_by_name(ellps)
+            .map(|(a, rf)| (a.to_string(), rf.to_string()))
+            .ok_or_else(|| Error::Wkt(format!("Unsupported ellipsoid '{ellps}'").into()))
+    }
+}
+
+// Find the method mapping with a WKT name which matches the most
+// parameters of the proj string
+fn find_mapping(
+    proj: &str,
+    ps: &ProjString,
+    wkt_name: fn(&MethodMapping) -> &'static str,
+) -> Option<&'static MethodMapping> {
+    let mut best: Option<(&'static MethodMapping, usize)> = None;
+    for m in method_mappings()
+        .iter()
+        .filter(|m| m.proj_name() == proj && m.proj_aux().is_empty() && !wkt_name(m).is_empty())
+    {
+        let score = m
+            .parameters()
+            .iter()
+            .filter(|pm| !pm.proj_name.is_empty() && ps.has(pm.proj_name))
+            .count();
+        if best.map_or(true, |(_, s)| score > s) {
+            best = Some((m, score));
+        }
+    }
+    best.map(|(m, _)| m)
+}